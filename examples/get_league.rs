@@ -7,7 +7,7 @@ async fn main() -> anyhow::Result<()> {
     let arg = std::env::args().nth(1).unwrap_or_else(|| "293".to_owned());
 
     let get_league = arg.parse::<u64>().map_or_else(
-        |_| GetLeague(Identifier::Slug(&arg)),
+        |_| GetLeague(Identifier::slug(arg.as_str())),
         |id| GetLeague(Identifier::Id(id)),
     );
 
@@ -13,7 +13,7 @@ async fn main() -> anyhow::Result<()> {
 
     let id = arg
         .parse::<u64>()
-        .map_or_else(|_| Identifier::Slug(&arg), Identifier::Id);
+        .map_or_else(|_| Identifier::slug(arg.as_str()), Identifier::Id);
     let status = match status.as_deref() {
         None => None,
         Some("past") => Some(EventStatus::Past),
@@ -8,7 +8,7 @@ async fn main() -> anyhow::Result<()> {
 
     let id = arg
         .parse::<u64>()
-        .map_or_else(|_| Identifier::Slug(&arg), Identifier::Id);
+        .map_or_else(|_| Identifier::slug(arg.as_str()), Identifier::Id);
     let get_league_series = ListLeagueSeries::builder().id(id).build();
 
     let client = Client::new(reqwest::Client::new(), token)?;
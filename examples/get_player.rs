@@ -10,7 +10,7 @@ async fn main() -> anyhow::Result<()> {
 
     let get_player = GetPlayer(
         arg.parse::<u64>()
-            .map_or_else(|_| Identifier::Slug(&arg), Identifier::Id),
+            .map_or_else(|_| Identifier::slug(arg.as_str()), Identifier::Id),
     );
 
     let client = Client::new(reqwest::Client::new(), token)?;
@@ -1,10 +1,16 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use reqwest::{
     header::{HeaderValue, InvalidHeaderValue, ACCEPT, AUTHORIZATION},
     Error, Request, Response,
 };
 use tower::{Service, ServiceExt};
 
-use crate::endpoint::{Endpoint, EndpointError};
+use crate::endpoint::{
+    rate_limit_remaining, Endpoint, EndpointError, ListResponse, PaginatedEndpoint,
+    PaginationLimits,
+};
 
 /// A trait for the underlying HTTP client implementation.
 pub trait ClientTransport: Service<Request, Response = Response, Error = Error> + Clone {}
@@ -20,9 +26,12 @@ impl<T> ClientTransport for T where T: Service<Request, Response = Response, Err
 /// especially when [this reqwest issue](https://github.com/seanmonstar/reqwest/issues/2251) is
 /// resolved.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct Client<T> {
     client: T,
     auth_header: HeaderValue,
+    /// Remaining rate limit quota, shared between clones of the client.
+    rate_limit_remaining: Arc<Mutex<Option<u64>>>,
 }
 
 #[allow(clippy::future_not_send)]
@@ -45,9 +54,22 @@ impl<T: ClientTransport> Client<T> {
         Ok(Self {
             client,
             auth_header,
+            rate_limit_remaining: Arc::default(),
         })
     }
 
+    /// Returns the number of requests remaining in the current rate limit window.
+    ///
+    /// This is updated from the `X-Rate-Limit-Remaining` header of every response,
+    /// and is `None` until a response containing the header is received.
+    #[must_use]
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        *self
+            .rate_limit_remaining
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
     async fn execute_internal(&self, mut request: Request) -> Result<Response, Error> {
         request
             .headers_mut()
@@ -56,7 +78,14 @@ impl<T: ClientTransport> Client<T> {
             .headers_mut()
             .insert(ACCEPT, HeaderValue::from_static("application/json"));
 
-        self.client.clone().oneshot(request).await
+        let response = self.client.clone().oneshot(request).await?;
+        if let Some(remaining) = rate_limit_remaining(response.headers()) {
+            *self
+                .rate_limit_remaining
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(remaining);
+        }
+        Ok(response)
     }
 
     /// Execute the given request and return the response.
@@ -67,6 +96,11 @@ impl<T: ClientTransport> Client<T> {
     ///
     /// Returns: `Result<<R as Sealed>::Response, EndpointError>`
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails to build, the HTTP request fails,
+    /// or the response can't be deserialized.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -85,4 +119,101 @@ impl<T: ClientTransport> Client<T> {
     {
         R::from_response(self.execute_internal(request.to_request()?).await?).await
     }
+
+    /// Automatically paginate the given endpoint, returning a stream of items.
+    ///
+    /// Pages are requested lazily by following the `next` link of each response,
+    /// so dropping the stream early stops any further requests.
+    /// The stream ends after the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint`: a paginated endpoint to start from.
+    ///
+    /// Returns: `impl Stream<Item = Result<E::Item, EndpointError>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use pandascore::{Client, endpoint::all::leagues::ListLeagues};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = Client::new(reqwest::Client::new(), "token").unwrap();
+    /// let leagues: Vec<_> = client
+    ///     .paginate(ListLeagues::default())
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    /// println!("{:?}", leagues);
+    /// # }
+    /// ```
+    pub fn paginate<'a, E>(
+        &'a self,
+        endpoint: E,
+    ) -> impl Stream<Item = Result<E::Item, EndpointError>> + 'a
+    where
+        E: PaginatedEndpoint + Clone + 'a,
+    {
+        self.paginate_with(endpoint, PaginationLimits::default())
+    }
+
+    /// Automatically paginate the given endpoint with the given limits,
+    /// returning a stream of items.
+    ///
+    /// See [`Client::paginate`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint`: a paginated endpoint to start from.
+    /// * `limits`: the maximum number of pages and items to retrieve.
+    ///
+    /// Returns: `impl Stream<Item = Result<E::Item, EndpointError>>`
+    pub fn paginate_with<'a, E>(
+        &'a self,
+        endpoint: E,
+        limits: PaginationLimits,
+    ) -> impl Stream<Item = Result<E::Item, EndpointError>> + 'a
+    where
+        E: PaginatedEndpoint + Clone + 'a,
+    {
+        self.paginate_pages(endpoint, limits)
+            .map_ok(|page| stream::iter(page.results.into_iter().map(Ok)))
+            .try_flatten()
+            .take(limits.max_items.unwrap_or(usize::MAX))
+    }
+
+    /// Automatically paginate the given endpoint, returning a stream of pages.
+    ///
+    /// See [`Client::paginate`] for details.
+    /// [`PaginationLimits::max_items`] is ignored, as whole pages are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint`: a paginated endpoint to start from.
+    /// * `limits`: the maximum number of pages to retrieve.
+    ///
+    /// Returns: `impl Stream<Item = Result<ListResponse<E::Item>, EndpointError>>`
+    pub fn paginate_pages<'a, E>(
+        &'a self,
+        endpoint: E,
+        limits: PaginationLimits,
+    ) -> impl Stream<Item = Result<ListResponse<E::Item>, EndpointError>> + 'a
+    where
+        E: PaginatedEndpoint + Clone + 'a,
+    {
+        stream::try_unfold((Some(endpoint), 0), move |(endpoint, pages)| async move {
+            let Some(endpoint) = endpoint else {
+                return Ok(None);
+            };
+            if limits.max_pages.is_some_and(|max| pages >= max) {
+                return Ok(None);
+            }
+
+            let page = self.execute(endpoint.clone()).await?;
+            let next = page.next.clone().map(|next| endpoint.with_options(next));
+            Ok(Some((page, (next, pages + 1))))
+        })
+    }
 }
@@ -0,0 +1,96 @@
+use reqwest::{Request, Response};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::{
+    endpoint::{
+        options::QueryValue, sealed::Sealed, CollectionOptions, EndpointError, ListResponse,
+        PaginatedEndpoint, BASE_URL,
+    },
+    model::incident::{Incident, IncidentType},
+};
+
+macro_rules! incidents_endpoint {
+    ($(#[$meta:meta])* $name:ident($path:literal)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Eq, PartialEq, Default, bon::Builder)]
+        pub struct $name {
+            /// Only return incidents for the given entity types.
+            #[builder(default)]
+            pub types: Vec<IncidentType>,
+            /// Only return incidents modified after the given time.
+            pub since: Option<OffsetDateTime>,
+            #[builder(default, into)]
+            pub options: CollectionOptions,
+        }
+
+        impl Sealed for $name {
+            type Response = ListResponse<Incident>;
+
+            fn to_request(self) -> Result<Request, EndpointError> {
+                let mut url = Url::parse(&format!(concat!("{}", $path), BASE_URL))?;
+                self.options.add_params(&mut url);
+                if !self.types.is_empty() {
+                    let types = self.types.iter().map(IncidentType::as_str).collect::<Vec<_>>().join(",");
+                    url.query_pairs_mut().append_pair("type", &types);
+                }
+                if let Some(since) = self.since {
+                    url.query_pairs_mut()
+                        .append_pair("since", &since.to_query_value());
+                }
+                Ok(Request::new(reqwest::Method::GET, url))
+            }
+
+            async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
+                ListResponse::from_response(response).await
+            }
+        }
+
+        impl PaginatedEndpoint for $name {
+            type Item = Incident;
+
+            fn with_options(self, options: CollectionOptions) -> Self {
+                Self { options, ..self }
+            }
+        }
+    };
+}
+
+incidents_endpoint! {
+    /// Lists the latest additions, changes and deletions.
+    ListIncidents("/incidents")
+}
+incidents_endpoint! {
+    /// Lists the latest additions.
+    ListAdditions("/additions")
+}
+incidents_endpoint! {
+    /// Lists the latest changes.
+    ListChanges("/changes")
+}
+incidents_endpoint! {
+    /// Lists the latest deletions.
+    ListDeletions("/deletions")
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_list_incidents_request() {
+        let request = ListIncidents::builder()
+            .types(vec![IncidentType::Match, IncidentType::Series])
+            .since(datetime!(2024-09-01 12:00 UTC))
+            .build()
+            .to_request()
+            .unwrap();
+
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.pandascore.co/incidents?type=match%2Cserie&since=2024-09-01T12%3A00%3A00Z"
+        );
+    }
+}
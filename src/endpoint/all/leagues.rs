@@ -16,7 +16,7 @@ pub struct GetLeagueMatches<'a> {
     #[builder(into)]
     id: Identifier<'a>,
     status: Option<EventStatus>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -24,7 +24,7 @@ impl Sealed for GetLeagueMatches<'_> {
     type Response = ListResponse<Match>;
 
     fn to_request(self) -> Result<Request, EndpointError> {
-        let mut url = Url::parse(&format!("{}/leagues/{}/matches/", BASE_URL, self.id,))?;
+        let mut url = Url::parse(&format!("{}/leagues/{}/matches/", BASE_URL, self.id))?;
         self.options.add_params(&mut url);
         if let Some(status) = self.status {
             url = url.join(status.as_str())?;
@@ -49,7 +49,7 @@ impl PaginatedEndpoint for GetLeagueMatches<'_> {
 pub struct ListLeagueSeries<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -57,7 +57,7 @@ impl Sealed for ListLeagueSeries<'_> {
     type Response = ListResponse<Series>;
 
     fn to_request(self) -> Result<Request, EndpointError> {
-        let mut url = Url::parse(&format!("{}/leagues/{}/series", BASE_URL, self.id,))?;
+        let mut url = Url::parse(&format!("{}/leagues/{}/series", BASE_URL, self.id))?;
         self.options.add_params(&mut url);
         Ok(Request::new(Method::GET, url))
     }
@@ -84,7 +84,7 @@ mod tests {
         let id = 1;
         let slug = "slug";
         let get_league_id = GetLeague(Identifier::Id(id));
-        let get_league_slug = GetLeague(Identifier::Slug(slug));
+        let get_league_slug = GetLeague(Identifier::from(slug));
 
         let request_id = get_league_id.to_request().unwrap();
         let request_slug = get_league_slug.to_request().unwrap();
@@ -0,0 +1,3 @@
+use crate::model::live::Live;
+
+crate::endpoint::list_endpoint!(ListLives("/lives") => Live);
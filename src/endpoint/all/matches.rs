@@ -12,7 +12,7 @@ use crate::{
 crate::endpoint::multi_list_endpoint!(ListMatches("/matches") => Match);
 crate::endpoint::get_endpoint!(GetMatch("/matches") => Match);
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct GetMatchOpponents<'a>(pub Identifier<'a>);
 
 impl Sealed for GetMatchOpponents<'_> {
@@ -24,7 +24,7 @@ impl Sealed for GetMatchOpponents<'_> {
     }
 
     async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
-        crate::endpoint::deserialize(response.error_for_status()?).await
+        crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?).await
     }
 }
 
@@ -1,8 +1,11 @@
 //! Endpoints generic across all games.
 
+pub mod incidents;
 pub mod leagues;
+pub mod lives;
 pub mod matches;
 pub mod players;
 pub mod series;
 pub mod teams;
 pub mod tournament;
+pub mod videogames;
@@ -17,7 +17,7 @@ crate::endpoint::get_endpoint!(GetPlayer("/players") => crate::model::player::Pl
 pub struct ListPlayerLeagues<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -49,7 +49,7 @@ impl PaginatedEndpoint for ListPlayerLeagues<'_> {
 pub struct ListPlayerSeries<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -81,7 +81,7 @@ impl PaginatedEndpoint for ListPlayerSeries<'_> {
 pub struct ListPlayerTournaments<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -113,7 +113,7 @@ impl PaginatedEndpoint for ListPlayerTournaments<'_> {
 pub struct ListPlayerMatches<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
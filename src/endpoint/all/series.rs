@@ -16,7 +16,7 @@ pub struct ListSeriesMatches<'a> {
     #[builder(into)]
     id: Identifier<'a>,
     status: Option<EventStatus>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -24,7 +24,7 @@ impl Sealed for ListSeriesMatches<'_> {
     type Response = ListResponse<Match>;
 
     fn to_request(self) -> Result<Request, EndpointError> {
-        let mut url = Url::parse(&format!("{}/series/{}/matches/", BASE_URL, self.id,))?;
+        let mut url = Url::parse(&format!("{}/series/{}/matches/", BASE_URL, self.id))?;
         self.options.add_params(&mut url);
         if let Some(status) = self.status {
             url = url.join(status.as_str())?;
@@ -37,11 +37,19 @@ impl Sealed for ListSeriesMatches<'_> {
     }
 }
 
+impl PaginatedEndpoint for ListSeriesMatches<'_> {
+    type Item = Match;
+
+    fn with_options(self, options: CollectionOptions) -> Self {
+        Self { options, ..self }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
 pub struct ListSeriesTournaments<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -49,7 +57,7 @@ impl Sealed for ListSeriesTournaments<'_> {
     type Response = ListResponse<Tournament>;
 
     fn to_request(self) -> Result<Request, EndpointError> {
-        let mut url = Url::parse(&format!("{}/series/{}/tournaments", BASE_URL, self.id,))?;
+        let mut url = Url::parse(&format!("{}/series/{}/tournaments", BASE_URL, self.id))?;
         self.options.add_params(&mut url);
         Ok(Request::new(reqwest::Method::GET, url))
     }
@@ -17,7 +17,7 @@ crate::endpoint::get_endpoint!(GetTeam("/teams") => crate::model::team::Team);
 pub struct ListTeamLeagues<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -49,7 +49,7 @@ impl PaginatedEndpoint for ListTeamLeagues<'_> {
 pub struct ListTeamSeries<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -81,7 +81,7 @@ impl PaginatedEndpoint for ListTeamSeries<'_> {
 pub struct ListTeamTournaments<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -113,7 +113,7 @@ impl PaginatedEndpoint for ListTeamTournaments<'_> {
 pub struct ListTeamMatches<'a> {
     #[builder(into)]
     id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     options: CollectionOptions,
 }
 
@@ -19,7 +19,7 @@ use crate::{
 crate::endpoint::multi_list_endpoint!(ListTournaments("/tournaments") => crate::model::tournament::Tournament);
 crate::endpoint::get_endpoint!(GetTournament("/tournaments") => crate::model::tournament::Tournament);
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct GetTournamentBracket<'a>(pub Identifier<'a>);
 
 //TODO: add pagination
@@ -33,7 +33,8 @@ impl Sealed for GetTournamentBracket<'_> {
 
     async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
         let bracket: Vec<TournamentBracketMatch> =
-            crate::endpoint::deserialize(response.error_for_status()?).await?;
+            crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?)
+                .await?;
         Ok(TournamentBracket::new(bracket))
     }
 }
@@ -51,7 +52,7 @@ where
 pub struct ListTournamentMatches<'a> {
     #[builder(into)]
     pub id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     pub options: CollectionOptions,
 }
 
@@ -83,7 +84,7 @@ impl PaginatedEndpoint for ListTournamentMatches<'_> {
 pub struct ListTournamentTeams<'a> {
     #[builder(into)]
     pub id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     pub options: CollectionOptions,
 }
 
@@ -111,7 +112,7 @@ impl PaginatedEndpoint for ListTournamentTeams<'_> {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct GetTournamentRosters<'a>(pub Identifier<'a>);
 
 impl Sealed for GetTournamentRosters<'_> {
@@ -123,7 +124,7 @@ impl Sealed for GetTournamentRosters<'_> {
     }
 
     async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
-        crate::endpoint::deserialize(response.error_for_status()?).await
+        crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?).await
     }
 }
 
@@ -140,7 +141,7 @@ where
 pub struct GetTournamentStandings<'a> {
     #[builder(into)]
     pub id: Identifier<'a>,
-    #[builder(default)]
+    #[builder(default, into)]
     pub options: CollectionOptions,
 }
 
@@ -0,0 +1,97 @@
+use reqwest::{Request, Response};
+use url::Url;
+
+use crate::{
+    endpoint::{
+        sealed::Sealed, CollectionOptions, EndpointError, ListResponse, PaginatedEndpoint, BASE_URL,
+    },
+    model::{
+        league::League, series::Series, tournament::Tournament, Identifier, VideoGame,
+        VideoGameVersion,
+    },
+};
+
+crate::endpoint::list_endpoint!(ListVideoGames("/videogames") => VideoGame);
+crate::endpoint::get_endpoint!(GetVideoGame("/videogames") => VideoGame);
+
+macro_rules! videogame_list_endpoint {
+    ($(#[$meta:meta])* $name:ident($path:literal) => $response:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
+        pub struct $name<'a> {
+            #[builder(into)]
+            id: Identifier<'a>,
+            #[builder(default, into)]
+            options: CollectionOptions,
+        }
+
+        impl Sealed for $name<'_> {
+            type Response = ListResponse<$response>;
+
+            fn to_request(self) -> Result<Request, EndpointError> {
+                let mut url = Url::parse(&format!(
+                    concat!("{}/videogames/{}", $path),
+                    BASE_URL, self.id
+                ))?;
+                self.options.add_params(&mut url);
+                Ok(Request::new(reqwest::Method::GET, url))
+            }
+
+            async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
+                ListResponse::from_response(response).await
+            }
+        }
+
+        impl PaginatedEndpoint for $name<'_> {
+            type Item = $response;
+
+            fn with_options(self, options: CollectionOptions) -> Self {
+                Self { options, ..self }
+            }
+        }
+    };
+}
+
+videogame_list_endpoint! {
+    /// Lists the versions of a video game.
+    ListVideoGameVersions("/versions") => VideoGameVersion
+}
+videogame_list_endpoint! {
+    /// Lists the leagues of a video game.
+    ListVideoGameLeagues("/leagues") => League
+}
+videogame_list_endpoint! {
+    /// Lists the series of a video game.
+    ListVideoGameSeries("/series") => Series
+}
+videogame_list_endpoint! {
+    /// Lists the tournaments of a video game.
+    ListVideoGameTournaments("/tournaments") => Tournament
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::VideoGameSlug;
+
+    #[test]
+    fn test_get_video_game() {
+        let request = GetVideoGame::from(&VideoGameSlug::RocketLeague)
+            .to_request()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.pandascore.co/videogames/rl"
+        );
+    }
+
+    #[test]
+    fn test_list_video_game_versions() {
+        let request = ListVideoGameVersions::builder()
+            .id(1)
+            .build()
+            .to_request()
+            .unwrap();
+        assert_eq!(request.url().path(), "/videogames/1/versions");
+    }
+}
@@ -0,0 +1,3 @@
+//! # Call of Duty Endpoints
+
+crate::endpoint::game_endpoints!("codmw");
@@ -0,0 +1,39 @@
+use reqwest::{Request, Response};
+use url::Url;
+
+use crate::{
+    endpoint::{
+        sealed::Sealed, CollectionOptions, EndpointError, ListResponse, PaginatedEndpoint, BASE_URL,
+    },
+    model::csgo::round::Round,
+};
+
+/// Lists the rounds of a game.
+#[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
+pub struct ListGameRounds {
+    game_id: u64,
+    #[builder(default, into)]
+    options: CollectionOptions,
+}
+
+impl Sealed for ListGameRounds {
+    type Response = ListResponse<Round>;
+
+    fn to_request(self) -> Result<Request, EndpointError> {
+        let mut url = Url::parse(&format!("{}/csgo/games/{}/rounds", BASE_URL, self.game_id))?;
+        self.options.add_params(&mut url);
+        Ok(Request::new(reqwest::Method::GET, url))
+    }
+
+    async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
+        ListResponse::from_response(response).await
+    }
+}
+
+impl PaginatedEndpoint for ListGameRounds {
+    type Item = Round;
+
+    fn with_options(self, options: CollectionOptions) -> Self {
+        Self { options, ..self }
+    }
+}
@@ -0,0 +1,4 @@
+use crate::model::csgo::map::Map;
+
+crate::endpoint::list_endpoint!(ListMaps("/csgo/maps") => Map);
+crate::endpoint::get_endpoint!(GetMap("/csgo/maps") => Map);
@@ -0,0 +1,7 @@
+//! # Counter-Strike Endpoints
+
+pub mod games;
+pub mod maps;
+pub mod weapons;
+
+crate::endpoint::game_endpoints!("csgo");
@@ -0,0 +1,4 @@
+use crate::model::csgo::weapon::Weapon;
+
+crate::endpoint::list_endpoint!(ListWeapons("/csgo/weapons") => Weapon);
+crate::endpoint::get_endpoint!(GetWeapon("/csgo/weapons") => Weapon);
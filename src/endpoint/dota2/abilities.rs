@@ -0,0 +1,4 @@
+use crate::model::dota2::ability::Ability;
+
+crate::endpoint::list_endpoint!(ListAbilities("/dota2/abilities") => Ability);
+crate::endpoint::get_endpoint!(GetAbility("/dota2/abilities") => Ability);
@@ -0,0 +1,4 @@
+use crate::model::dota2::hero::Hero;
+
+crate::endpoint::list_endpoint!(ListHeroes("/dota2/heroes") => Hero);
+crate::endpoint::get_endpoint!(GetHero("/dota2/heroes") => Hero);
@@ -0,0 +1,4 @@
+use crate::model::dota2::item::Item;
+
+crate::endpoint::list_endpoint!(ListItems("/dota2/items") => Item);
+crate::endpoint::get_endpoint!(GetItem("/dota2/items") => Item);
@@ -0,0 +1,7 @@
+//! # Dota 2 Endpoints
+
+pub mod abilities;
+pub mod heroes;
+pub mod items;
+
+crate::endpoint::game_endpoints!("dota2");
@@ -0,0 +1,3 @@
+//! # EA Sports FC Endpoints
+
+crate::endpoint::game_endpoints!("fifa");
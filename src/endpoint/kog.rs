@@ -0,0 +1,3 @@
+//! # King of Glory Endpoints
+
+crate::endpoint::game_endpoints!("kog");
@@ -20,6 +20,6 @@ impl Sealed for GetChampion {
     }
 
     async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
-        crate::endpoint::deserialize(response.error_for_status()?).await
+        crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?).await
     }
 }
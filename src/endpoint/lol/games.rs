@@ -0,0 +1 @@
+crate::endpoint::games_endpoints!("lol" => crate::model::lol::game::Game);
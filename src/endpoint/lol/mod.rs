@@ -1,7 +1,9 @@
 //! # League of Legends Endpoints
 
 pub mod champions;
+pub mod games;
 pub mod items;
 pub mod spells;
+pub mod stats;
 
 crate::endpoint::game_endpoints!("lol");
@@ -0,0 +1,124 @@
+//! Statistics of League of Legends players and teams,
+//! optionally restricted to a series or tournament.
+//!
+//! Champion statistics are only available as the champion pools of players and teams,
+//! see [`ChampionStats`](crate::model::lol::stats::ChampionStats).
+//! Statistics per champion are out of scope,
+//! as the API has no champion statistics endpoint to wrap.
+
+use std::fmt::Display;
+
+use reqwest::{Request, Response};
+use url::Url;
+
+use crate::{
+    endpoint::{sealed::Sealed, EndpointError, BASE_URL},
+    model::{
+        lol::stats::{PlayerStats, TeamStats},
+        Identifier,
+    },
+};
+
+/// Restricts statistics to the games of a series or tournament.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum StatsScope<'a> {
+    /// Statistics across all games.
+    #[default]
+    All,
+    Series(Identifier<'a>),
+    Tournament(Identifier<'a>),
+}
+
+impl StatsScope<'_> {
+    fn url(&self, resource: &str, id: impl Display) -> Result<Url, url::ParseError> {
+        let url = match self {
+            Self::All => format!("{BASE_URL}/lol/{resource}/{id}/stats"),
+            Self::Series(series) => {
+                format!("{BASE_URL}/lol/series/{series}/{resource}/{id}/stats")
+            }
+            Self::Tournament(tournament) => {
+                format!("{BASE_URL}/lol/tournaments/{tournament}/{resource}/{id}/stats")
+            }
+        };
+        Url::parse(&url)
+    }
+}
+
+/// Gets the statistics of a player.
+#[derive(Debug, Clone, Eq, PartialEq, bon::Builder)]
+pub struct GetPlayerStats<'a> {
+    #[builder(into)]
+    id: Identifier<'a>,
+    #[builder(default)]
+    scope: StatsScope<'a>,
+}
+
+impl Sealed for GetPlayerStats<'_> {
+    type Response = PlayerStats;
+
+    fn to_request(self) -> Result<Request, EndpointError> {
+        let url = self.scope.url("players", self.id)?;
+        Ok(Request::new(reqwest::Method::GET, url))
+    }
+
+    async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
+        crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?).await
+    }
+}
+
+/// Gets the statistics of a team.
+#[derive(Debug, Clone, Eq, PartialEq, bon::Builder)]
+pub struct GetTeamStats<'a> {
+    #[builder(into)]
+    id: Identifier<'a>,
+    #[builder(default)]
+    scope: StatsScope<'a>,
+}
+
+impl Sealed for GetTeamStats<'_> {
+    type Response = TeamStats;
+
+    fn to_request(self) -> Result<Request, EndpointError> {
+        let url = self.scope.url("teams", self.id)?;
+        Ok(Request::new(reqwest::Method::GET, url))
+    }
+
+    async fn from_response(response: Response) -> Result<Self::Response, EndpointError> {
+        crate::endpoint::deserialize(crate::endpoint::error_for_status(response).await?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_scope() {
+        let request = GetPlayerStats::builder()
+            .id("faker")
+            .build()
+            .to_request()
+            .unwrap();
+        assert_eq!(request.url().path(), "/lol/players/faker/stats");
+
+        let request = GetPlayerStats::builder()
+            .id(585)
+            .scope(StatsScope::Series(Identifier::Id(7861)))
+            .build()
+            .to_request()
+            .unwrap();
+        assert_eq!(request.url().path(), "/lol/series/7861/players/585/stats");
+
+        let request = GetTeamStats::builder()
+            .id(126_061)
+            .scope(StatsScope::Tournament(Identifier::from("worlds-2024")))
+            .build()
+            .to_request()
+            .unwrap();
+        assert_eq!(
+            request.url().path(),
+            "/lol/tournaments/worlds-2024/teams/126061/stats"
+        );
+    }
+}
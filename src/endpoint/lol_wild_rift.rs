@@ -0,0 +1,3 @@
+//! # League of Legends: Wild Rift Endpoints
+
+crate::endpoint::game_endpoints!("lol-wild-rift");
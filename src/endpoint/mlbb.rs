@@ -0,0 +1,3 @@
+//! # Mobile Legends: Bang Bang Endpoints
+
+crate::endpoint::game_endpoints!("mlbb");
@@ -1,20 +1,40 @@
 //! Endpoints for the `PandaScore` API.
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
     ops::{Deref, DerefMut},
     sync::OnceLock,
+    time::Duration,
 };
 
 use compact_str::{format_compact, CompactString, CompactStringExt, ToCompactString};
 use linkify::{Link, LinkFinder, LinkKind};
 use regex::Regex;
-use reqwest::header::{AsHeaderName, LINK};
-use serde::de::DeserializeOwned;
+use reqwest::{
+    header::{AsHeaderName, HeaderMap, LINK, RETRY_AFTER},
+    StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use time::{format_description::BorrowedFormatItem, OffsetDateTime, PrimitiveDateTime};
 
 pub mod all;
+pub mod codmw;
+pub mod csgo;
+pub mod dota2;
+pub mod fifa;
+pub mod kog;
 pub mod lol;
+pub mod lol_wild_rift;
+pub mod mlbb;
+pub mod options;
+pub mod ow;
+pub mod pubg;
+pub mod r6siege;
 pub mod rl;
+pub mod starcraft2;
+pub mod starcraft_brood_war;
+pub mod valorant;
 
 const BASE_URL: &str = "https://api.pandascore.co";
 
@@ -40,22 +60,157 @@ pub trait Endpoint: sealed::Sealed {}
 
 impl<T: sealed::Sealed> Endpoint for T {}
 
-pub trait PaginatedEndpoint: Endpoint {
+/// An endpoint that returns a paginated collection of items.
+///
+/// Paginated endpoints can be driven automatically using [`Client::paginate`] and
+/// [`Client::paginate_pages`].
+///
+/// [`Client::paginate`]: crate::Client::paginate
+/// [`Client::paginate_pages`]: crate::Client::paginate_pages
+pub trait PaginatedEndpoint:
+    Endpoint + sealed::Sealed<Response = ListResponse<Self::Item>>
+{
     type Item;
 
     #[must_use]
     fn with_options(self, options: CollectionOptions) -> Self;
 }
 
+/// Limits applied when automatically paginating an endpoint.
+///
+/// By default, pagination continues until the API stops returning a `next` link.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, bon::Builder)]
+pub struct PaginationLimits {
+    /// Maximum number of pages to request.
+    pub max_pages: Option<usize>,
+    /// Maximum number of items to yield.
+    pub max_items: Option<usize>,
+}
+
 async fn deserialize<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, EndpointError> {
     let body = response.bytes().await?;
     let mut jd = serde_json::Deserializer::from_slice(body.as_ref());
     Ok(serde_path_to_error::deserialize(&mut jd)?)
 }
 
+/// Checks the status code of the response,
+/// converting an unsuccessful response into the matching [`EndpointError`].
+async fn error_for_status(response: reqwest::Response) -> Result<reqwest::Response, EndpointError> {
+    let status = response.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        return Ok(response);
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after(response.headers());
+        return Err(EndpointError::RateLimited { retry_after });
+    }
+
+    let body = response.bytes().await?;
+    // The error body isn't guaranteed to be JSON, e.g. when returned by a proxy.
+    let body = serde_json::from_slice::<ApiErrorBody>(&body).unwrap_or_default();
+    let error = ApiError {
+        status,
+        error: body.error,
+        message: body.message,
+    };
+
+    Err(match status {
+        StatusCode::UNAUTHORIZED => EndpointError::Unauthorized(error),
+        StatusCode::FORBIDDEN => EndpointError::Forbidden(error),
+        StatusCode::NOT_FOUND => EndpointError::NotFound(error),
+        StatusCode::UNPROCESSABLE_ENTITY => EndpointError::UnprocessableEntity(error),
+        _ => EndpointError::Api(error),
+    })
+}
+
+/// Parses the remaining rate limit quota from the `X-Rate-Limit-Remaining` header.
+pub(crate) fn rate_limit_remaining(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get("X-Rate-Limit-Remaining")?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Parses the `Retry-After` header,
+/// either as a number of seconds or as an HTTP date.
+///
+/// Dates in the past are returned as a zero duration.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    const HTTP_DATE: &[BorrowedFormatItem<'static>] = time::macros::format_description!(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+    );
+
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = PrimitiveDateTime::parse(value, HTTP_DATE)
+        .ok()?
+        .assume_utc();
+    let delay = date - OffsetDateTime::now_utc();
+    Some(delay.try_into().unwrap_or(Duration::ZERO))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ApiErrorBody {
+    error: Option<CompactString>,
+    message: Option<CompactString>,
+}
+
+/// An error response returned by the `PandaScore` API.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ApiError {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The `error` field of the response body, if present.
+    pub error: Option<CompactString>,
+    /// The `message` field of the response body, if present.
+    pub message: Option<CompactString>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.status)?;
+        if let Some(error) = &self.error {
+            write!(f, ": {error}")?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, " ({message})")?;
+        }
+        Ok(())
+    }
+}
+
 /// Represents an error that occurred while interacting with an endpoint.
 #[derive(Debug, thiserror::Error)]
 pub enum EndpointError {
+    /// The API rejected the token (HTTP 401).
+    #[error("Unauthorized: {0}")]
+    Unauthorized(ApiError),
+    /// The endpoint isn't included in the token's plan (HTTP 403).
+    #[error("Forbidden: {0}")]
+    Forbidden(ApiError),
+    /// The requested resource doesn't exist, e.g. an unknown ID or slug (HTTP 404).
+    #[error("Not found: {0}")]
+    NotFound(ApiError),
+    /// The request parameters were invalid, e.g. a bad filter (HTTP 422).
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(ApiError),
+    /// The rate limit of the token has been exceeded (HTTP 429).
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        /// The duration to wait before retrying, parsed from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+    /// Any other unsuccessful response from the API.
+    #[error("API error: {0}")]
+    Api(ApiError),
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
     #[error(transparent)]
@@ -66,19 +221,84 @@ pub enum EndpointError {
     ToStr(#[from] reqwest::header::ToStrError),
     #[error("Failed to parse integer: {0}")]
     InvalidInt(#[from] std::num::ParseIntError),
+    /// A range in a pagination link doesn't have exactly two bounds.
+    #[error("Invalid range: {0}")]
+    InvalidRange(CompactString),
+}
+
+impl EndpointError {
+    /// Returns the error response returned by the API, if any.
+    #[must_use]
+    pub const fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::Unauthorized(error)
+            | Self::Forbidden(error)
+            | Self::NotFound(error)
+            | Self::UnprocessableEntity(error)
+            | Self::Api(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code returned by the API, if any.
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        self.api_error()
+            .map(|error| error.status)
+            .or_else(|| match self {
+                Self::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+                Self::Reqwest(error) => error.status(),
+                _ => None,
+            })
+    }
+
+    /// Returns `true` if the rate limit of the token has been exceeded.
+    #[must_use]
+    pub const fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited { .. })
+    }
+
+    /// Returns `true` if the API rejected the token.
+    #[must_use]
+    pub const fn is_unauthorized(&self) -> bool {
+        matches!(self, Self::Unauthorized(_))
+    }
+
+    /// Returns `true` if the endpoint isn't included in the token's plan.
+    #[must_use]
+    pub const fn is_forbidden_by_plan(&self) -> bool {
+        matches!(self, Self::Forbidden(_))
+    }
+
+    /// Returns `true` if the requested resource doesn't exist.
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound(_))
+    }
+
+    /// Returns `true` if the request parameters were rejected by the API.
+    #[must_use]
+    pub const fn is_unprocessable(&self) -> bool {
+        matches!(self, Self::UnprocessableEntity(_))
+    }
 }
 
 /// Options for filtering, searching, sorting, and paginating a collection.
-#[derive(Debug, Clone, Eq, PartialEq, Default)]
+///
+/// Options are serialized deterministically:
+/// filters, searches, and ranges are ordered by key,
+/// while sorts keep the order they were added in.
+/// The same options therefore always produce the same query string.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
 pub struct CollectionOptions {
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#filter>
-    filters: HashMap<CompactString, Vec<CompactString>>,
+    filters: BTreeMap<CompactString, Vec<CompactString>>,
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#search>
-    search: HashMap<CompactString, CompactString>,
+    search: BTreeMap<CompactString, CompactString>,
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#range>
-    range: HashMap<CompactString, (i64, i64)>,
+    range: BTreeMap<CompactString, (CompactString, CompactString)>,
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#sort>
-    sort: HashSet<CompactString>,
+    sort: Vec<CompactString>,
 
     /// <https://developers.pandascore.co/docs/pagination#page-number>
     page: Option<u32>,
@@ -94,7 +314,8 @@ impl CollectionOptions {
     }
 
     /// Adds a filter to the collection options.
-    /// If the filter already exists, the value is appended to the existing values.
+    /// If the filter already exists, the value is appended to the existing values,
+    /// unless it is already present.
     ///
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#filter>
     #[must_use]
@@ -103,10 +324,11 @@ impl CollectionOptions {
         key: impl Into<CompactString>,
         value: impl Into<CompactString>,
     ) -> Self {
-        self.filters
-            .entry(key.into())
-            .or_default()
-            .push(value.into());
+        let values = self.filters.entry(key.into()).or_default();
+        let value = value.into();
+        if !values.contains(&value) {
+            values.push(value);
+        }
         self
     }
 
@@ -129,18 +351,35 @@ impl CollectionOptions {
     ///
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#range>
     #[must_use]
-    pub fn range(mut self, key: impl Into<CompactString>, start: i64, end: i64) -> Self {
-        self.range.insert(key.into(), (start, end));
+    pub fn range(self, key: impl Into<CompactString>, start: i64, end: i64) -> Self {
+        self.range_str(key, start.to_compact_string(), end.to_compact_string())
+    }
+
+    /// Adds a range with non-integer bounds to the collection options,
+    /// e.g. ISO 8601 timestamps.
+    /// If the range already exists, the value is overwritten.
+    ///
+    /// <https://developers.pandascore.co/docs/filtering-and-sorting#range>
+    #[must_use]
+    pub fn range_str(
+        mut self,
+        key: impl Into<CompactString>,
+        start: impl Into<CompactString>,
+        end: impl Into<CompactString>,
+    ) -> Self {
+        self.range.insert(key.into(), (start.into(), end.into()));
         self
     }
 
     /// Adds a sort to the collection options.
     /// If a sort already exists, the value is appended to the existing values as a secondary sort.
     ///
+    /// If the field is already sorted on, in either direction, the new sort is ignored.
+    ///
     /// <https://developers.pandascore.co/docs/filtering-and-sorting#sort>
     #[must_use]
     pub fn sort(mut self, key: impl Into<CompactString>) -> Self {
-        self.sort.insert(key.into());
+        push_sort(&mut self.sort, key.into());
         self
     }
 
@@ -162,39 +401,53 @@ impl CollectionOptions {
         self
     }
 
-    fn add_params(self, url: &mut url::Url) {
-        let mut query = url.query_pairs_mut();
+    /// Returns the URL-encoded query string for the collection options,
+    /// without the leading `?`.
+    ///
+    /// The same options always produce the same query string.
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.query_pairs())
+            .finish()
+    }
+
+    fn add_params(&self, url: &mut url::Url) {
+        url.query_pairs_mut().extend_pairs(self.query_pairs());
+    }
+
+    fn query_pairs(&self) -> Vec<(CompactString, CompactString)> {
+        let mut pairs = Vec::new();
 
-        for (key, values) in self.filters {
+        for (key, values) in &self.filters {
             let key = format_compact!("filter[{}]", key);
-            let value = values.join_compact(",");
-            query.append_pair(&key, &value);
+            pairs.push((key, values.join_compact(",")));
         }
 
-        for (key, value) in self.search {
+        for (key, value) in &self.search {
             let key = format_compact!("search[{}]", key);
-            query.append_pair(&key, &value);
+            pairs.push((key, value.clone()));
         }
 
-        for (key, (start, end)) in self.range {
+        for (key, (start, end)) in &self.range {
             let key = format_compact!("range[{}]", key);
-            let value = format!("{start},{end}");
-            query.append_pair(&key, &value);
+            pairs.push((key, format_compact!("{start},{end}")));
         }
 
         if !self.sort.is_empty() {
-            let value = self.sort.join_compact(",");
-            query.append_pair("sort", &value);
+            pairs.push(("sort".into(), self.sort.join_compact(",")));
         }
 
         if let Some(page) = self.page {
-            // query.append_pair("page[number]", &page.to_compact_string());
-            query.append_pair("page", &page.to_compact_string());
+            // pairs.push(("page[number]".into(), page.to_compact_string()));
+            pairs.push(("page".into(), page.to_compact_string()));
         }
         if let Some(per_page) = self.per_page {
-            // query.append_pair("page[size]", &per_page.to_compact_string());
-            query.append_pair("per_page", &per_page.to_compact_string());
+            // pairs.push(("page[size]".into(), per_page.to_compact_string()));
+            pairs.push(("per_page".into(), per_page.to_compact_string()));
         }
+
+        pairs
     }
 
     fn from_url(url: &str) -> Result<Self, EndpointError> {
@@ -213,8 +466,9 @@ impl CollectionOptions {
                         continue;
                     };
                     let key = key.as_str().to_compact_string();
-                    let value = value.split(',').map(CompactString::from).collect();
-                    ret.filters.insert(key, value);
+                    for value in value.split(',') {
+                        ret = ret.filter(key.clone(), value);
+                    }
                 }
                 "search" => {
                     let Some(key) = captures.get(3) else {
@@ -228,27 +482,35 @@ impl CollectionOptions {
                         continue;
                     };
                     let key = key.as_str().to_compact_string();
-                    let Some((start, end)) = value.split_once(',') else {
-                        continue;
+                    let Some((start, end)) = value
+                        .split_once(',')
+                        .filter(|(start, end)| !start.is_empty() && !end.is_empty())
+                        .filter(|(_, end)| !end.contains(','))
+                    else {
+                        return Err(EndpointError::InvalidRange(value.to_compact_string()));
                     };
-                    let start = start.parse()?;
-                    let end = end.parse()?;
-                    ret.range.insert(key, (start, end));
+                    ret.range
+                        .insert(key, (start.to_compact_string(), end.to_compact_string()));
+                }
+                "sort" => {
+                    ret.sort.clear();
+                    for key in value.split(',') {
+                        push_sort(&mut ret.sort, key.into());
+                    }
                 }
-                "sort" => ret.sort = value.split(',').map(CompactString::from).collect(),
                 "page" => {
                     if let Some(tp) = captures.get(3) {
                         match tp.as_str() {
                             "number" => ret.page = Some(value.parse()?),
                             "size" => ret.per_page = Some(value.parse()?),
-                            _ => continue,
+                            _ => {}
                         }
                     } else {
                         ret.page = Some(value.parse()?);
                     }
                 }
                 "per_page" => ret.per_page = Some(value.parse()?),
-                _ => continue,
+                _ => {}
             }
         }
 
@@ -256,6 +518,23 @@ impl CollectionOptions {
     }
 }
 
+impl Display for CollectionOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_query_string())
+    }
+}
+
+/// Appends a sort key, ignoring it if the field is already sorted on.
+fn push_sort(sort: &mut Vec<CompactString>, key: CompactString) {
+    fn field(key: &str) -> &str {
+        key.strip_prefix('-').unwrap_or(key)
+    }
+
+    if !sort.iter().any(|existing| field(existing) == field(&key)) {
+        sort.push(key);
+    }
+}
+
 static KEY_REGEX: OnceLock<Regex> = OnceLock::new();
 
 fn get_key_regex() -> &'static Regex {
@@ -283,7 +562,7 @@ fn get_link_rel_regex() -> &'static Regex {
 
 impl<T: DeserializeOwned> ListResponse<T> {
     async fn from_response(response: reqwest::Response) -> Result<Self, EndpointError> {
-        let response = response.error_for_status()?;
+        let response = error_for_status(response).await?;
 
         let total = parse_header_int(&response, "X-Total")?.unwrap_or(0);
         let link_str = response
@@ -323,7 +602,7 @@ impl<T: DeserializeOwned> ListResponse<T> {
             match &captures[1] {
                 "next" => next = Some(CollectionOptions::from_url(link.as_str())?),
                 "prev" => prev = Some(CollectionOptions::from_url(link.as_str())?),
-                _ => continue,
+                _ => {}
             }
         }
 
@@ -425,7 +704,8 @@ macro_rules! get_endpoint {
             async fn from_response(
                 response: ::reqwest::Response,
             ) -> ::std::result::Result<Self::Response, $crate::endpoint::EndpointError> {
-                $crate::endpoint::deserialize(response.error_for_status()?).await
+                $crate::endpoint::deserialize($crate::endpoint::error_for_status(response).await?)
+                    .await
             }
         }
 
@@ -483,7 +763,7 @@ macro_rules! multi_list_endpoint {
         #[derive(Debug, Clone, Eq, PartialEq, Default, ::bon::Builder)]
         pub struct $name {
             pub status: ::std::option::Option<$crate::model::EventStatus>,
-            #[builder(default)]
+            #[builder(default, into)]
             pub options: $crate::endpoint::CollectionOptions,
         }
 
@@ -524,12 +804,156 @@ macro_rules! multi_list_endpoint {
 }
 pub(crate) use multi_list_endpoint;
 
+macro_rules! games_endpoints {
+    ($endpoint:literal => $game:ty) => {
+        /// Gets a game by ID.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub struct GetGame(pub u64);
+
+        impl $crate::endpoint::sealed::Sealed for GetGame {
+            type Response = $game;
+
+            fn to_request(
+                self,
+            ) -> std::result::Result<::reqwest::Request, $crate::endpoint::EndpointError> {
+                let url = ::url::Url::parse(&format!(
+                    concat!("{}/", $endpoint, "/games/{}"),
+                    $crate::endpoint::BASE_URL,
+                    self.0
+                ))?;
+                Ok(::reqwest::Request::new(::reqwest::Method::GET, url))
+            }
+
+            async fn from_response(
+                response: ::reqwest::Response,
+            ) -> ::std::result::Result<Self::Response, $crate::endpoint::EndpointError> {
+                $crate::endpoint::deserialize($crate::endpoint::error_for_status(response).await?)
+                    .await
+            }
+        }
+
+        /// Lists the games of a match.
+        #[derive(Debug, Clone, Eq, PartialEq, ::bon::Builder)]
+        pub struct ListMatchGames<'a> {
+            #[builder(into)]
+            pub match_id: $crate::model::Identifier<'a>,
+            #[builder(default, into)]
+            pub options: $crate::endpoint::CollectionOptions,
+        }
+
+        impl $crate::endpoint::sealed::Sealed for ListMatchGames<'_> {
+            type Response = $crate::endpoint::ListResponse<$game>;
+
+            fn to_request(
+                self,
+            ) -> std::result::Result<::reqwest::Request, $crate::endpoint::EndpointError> {
+                let mut url = ::url::Url::parse(&format!(
+                    concat!("{}/", $endpoint, "/matches/{}/games"),
+                    $crate::endpoint::BASE_URL,
+                    self.match_id
+                ))?;
+                self.options.add_params(&mut url);
+                Ok(::reqwest::Request::new(::reqwest::Method::GET, url))
+            }
+
+            fn from_response(
+                response: ::reqwest::Response,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Self::Response, $crate::endpoint::EndpointError>,
+            > + Send {
+                $crate::endpoint::ListResponse::from_response(response)
+            }
+        }
+
+        impl $crate::endpoint::PaginatedEndpoint for ListMatchGames<'_> {
+            type Item = $game;
+
+            fn with_options(self, options: $crate::endpoint::CollectionOptions) -> Self {
+                Self { options, ..self }
+            }
+        }
+    };
+}
+pub(crate) use games_endpoints;
+
 #[cfg(test)]
 mod tests {
     use url::Url;
 
     use super::*;
 
+    #[test]
+    fn test_retry_after() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, value.parse().unwrap());
+            headers
+        };
+
+        assert_eq!(retry_after(&headers("120")), Some(Duration::from_secs(120)));
+        assert_eq!(
+            retry_after(&headers("Sun, 06 Nov 1994 08:49:37 GMT")),
+            Some(Duration::ZERO)
+        );
+
+        let date = OffsetDateTime::now_utc() + time::Duration::hours(1);
+        let value = date
+            .format(time::macros::format_description!(
+                "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+            ))
+            .unwrap();
+        let delay = retry_after(&headers(&value)).unwrap();
+        assert!(delay > Duration::from_secs(3590) && delay <= Duration::from_secs(3600));
+
+        assert_eq!(retry_after(&headers("soon")), None);
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_supported_games() {
+        use crate::model::VideoGameSlug;
+
+        macro_rules! leagues_paths {
+            ($($game:ident => $module:ident),* $(,)?) => {
+                [$((
+                    VideoGameSlug::$game,
+                    sealed::Sealed::to_request($module::leagues::ListLeagues::default())
+                        .unwrap()
+                        .url()
+                        .path()
+                        .to_owned(),
+                )),*]
+            };
+        }
+        let paths = leagues_paths! {
+            CallOfDuty => codmw,
+            CounterStrike => csgo,
+            Dota2 => dota2,
+            EaSportsFc => fifa,
+            KingOfGlory => kog,
+            LeagueOfLegends => lol,
+            WildRift => lol_wild_rift,
+            MobileLegends => mlbb,
+            Overwatch => ow,
+            Pubg => pubg,
+            RainbowSix => r6siege,
+            RocketLeague => rl,
+            StarCraft2 => starcraft2,
+            StarCraftBroodWar => starcraft_brood_war,
+            Valorant => valorant,
+        };
+
+        assert_eq!(paths.len(), VideoGameSlug::ALL.len());
+        for game in VideoGameSlug::ALL {
+            let prefix = game.endpoint_prefix().unwrap();
+            let (_, path) = paths
+                .iter()
+                .find(|(slug, _)| *slug == game)
+                .unwrap_or_else(|| panic!("{game} has no endpoint module"));
+            assert_eq!(*path, format!("/{prefix}/leagues"), "{game}");
+        }
+    }
+
     #[test]
     fn test_collection_options_add_params() {
         let mut url = Url::parse("https://example.com").unwrap();
@@ -544,11 +968,80 @@ mod tests {
             .sort("-garply")
             .page(3)
             .per_page(4);
-        options.clone().add_params(&mut url);
+        options.add_params(&mut url);
 
         assert!(url.query().is_some());
 
         let options2 = CollectionOptions::from_url(url.as_str()).unwrap();
         assert_eq!(options, options2);
     }
+
+    #[test]
+    fn test_collection_options_invalid_range() {
+        for range in ["1", "1,", ",2", "1,2,3"] {
+            let url = format!("https://example.com?range[id]={range}");
+            assert!(
+                matches!(
+                    CollectionOptions::from_url(&url),
+                    Err(EndpointError::InvalidRange(_))
+                ),
+                "{range} should be rejected"
+            );
+        }
+
+        let url = "https://example.com?range[begin_at]=2024-01-01T00:00:00Z,2024-02-01T00:00:00Z";
+        let options = CollectionOptions::from_url(url).unwrap();
+        assert_eq!(
+            options,
+            CollectionOptions::new().range_str(
+                "begin_at",
+                "2024-01-01T00:00:00Z",
+                "2024-02-01T00:00:00Z"
+            )
+        );
+    }
+
+    #[test]
+    fn test_collection_options_sort_order() {
+        let options = CollectionOptions::new()
+            .sort("begin_at")
+            .sort("-id")
+            .sort("name")
+            .sort("-begin_at")
+            .sort("id");
+        assert_eq!(options.to_query_string(), "sort=begin_at%2C-id%2Cname");
+
+        let options2 =
+            CollectionOptions::from_url(&format!("https://example.com?{options}")).unwrap();
+        assert_eq!(options, options2);
+    }
+
+    #[test]
+    fn test_collection_options_deterministic() {
+        let options = CollectionOptions::new()
+            .filter("foo", "bar")
+            .filter("foo", "bar")
+            .filter("baz", "qux")
+            .search("b", "1")
+            .search("a", "2")
+            .range("y", 1, 2)
+            .range("x", 3, 4)
+            .page(1);
+        let options2 = CollectionOptions::new()
+            .page(1)
+            .range("x", 3, 4)
+            .range("y", 1, 2)
+            .search("a", "2")
+            .search("b", "1")
+            .filter("baz", "qux")
+            .filter("foo", "bar");
+
+        assert_eq!(options, options2);
+        assert_eq!(options.to_query_string(), options2.to_query_string());
+        assert_eq!(
+            options.to_string(),
+            "filter%5Bbaz%5D=qux&filter%5Bfoo%5D=bar&search%5Ba%5D=2&search%5Bb%5D=1\
+             &range%5Bx%5D=3%2C4&range%5By%5D=1%2C2&page=1"
+        );
+    }
 }
@@ -0,0 +1,609 @@
+//! Typed collection options for the main resources of the API.
+//!
+//! Each options type only exposes the filters, searches, ranges and sorts supported by its
+//! resource, and lowers into [`CollectionOptions`] for use with any endpoint.
+//!
+//! # Example
+//! ```rust
+//! use pandascore::{
+//!     endpoint::{
+//!         all::matches::ListMatches,
+//!         options::{MatchOptions, MatchSort, SortField},
+//!     },
+//!     model::matches::MatchStatus,
+//! };
+//! use time::macros::datetime;
+//!
+//! let options = MatchOptions::new()
+//!     .filter_status(MatchStatus::Running)
+//!     .range_begin_at(datetime!(2024-01-01 0:00 UTC)..datetime!(2024-02-01 0:00 UTC))
+//!     .sort_by(MatchSort::BeginAt.desc());
+//! let endpoint = ListMatches::builder().options(options).build();
+//! ```
+
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Bound, RangeBounds},
+};
+
+use compact_str::{CompactString, ToCompactString};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+
+use crate::{
+    endpoint::CollectionOptions,
+    model::{
+        matches::{MatchStatus, MatchType},
+        tournament::Tier,
+        VideoGameSlug,
+    },
+};
+
+/// A value that can be used in a filter or range of a collection.
+pub trait QueryValue {
+    /// Returns the representation of the value used in the query string.
+    fn to_query_value(&self) -> CompactString;
+}
+
+macro_rules! display_query_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl QueryValue for $ty {
+                fn to_query_value(&self) -> CompactString {
+                    self.to_compact_string()
+                }
+            }
+        )*
+    };
+}
+display_query_value!(u16, u32, u64, i64, bool, str, CompactString, String);
+
+macro_rules! as_str_query_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl QueryValue for $ty {
+                fn to_query_value(&self) -> CompactString {
+                    CompactString::const_new(self.as_str())
+                }
+            }
+        )*
+    };
+}
+as_str_query_value!(MatchStatus, MatchType, Tier);
+
+impl QueryValue for VideoGameSlug {
+    fn to_query_value(&self) -> CompactString {
+        self.as_str().into()
+    }
+}
+
+impl QueryValue for OffsetDateTime {
+    fn to_query_value(&self) -> CompactString {
+        let utc = self.to_offset(UtcOffset::UTC);
+        // Only fails for years outside of 0..=9999.
+        utc.format(&Rfc3339)
+            .map_or_else(|_| utc.unix_timestamp().to_compact_string(), Into::into)
+    }
+}
+
+impl<T: QueryValue + ?Sized> QueryValue for &T {
+    fn to_query_value(&self) -> CompactString {
+        (**self).to_query_value()
+    }
+}
+
+/// A value that can bound a range of a collection.
+///
+/// Ranges of the API are inclusive, so excluded bounds are converted to the closest included value.
+trait RangeValue: QueryValue + Copy {
+    /// Returns the closest value after this one.
+    fn successor(self) -> Self;
+    /// Returns the closest value before this one.
+    fn predecessor(self) -> Self;
+}
+
+macro_rules! int_range_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl RangeValue for $ty {
+                fn successor(self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn predecessor(self) -> Self {
+                    self.saturating_sub(1)
+                }
+            }
+        )*
+    };
+}
+int_range_value!(u16, u32, u64);
+
+/// Timestamps are compared to the second by the API.
+impl RangeValue for OffsetDateTime {
+    fn successor(self) -> Self {
+        self.saturating_add(time::Duration::SECOND)
+    }
+
+    fn predecessor(self) -> Self {
+        self.saturating_sub(time::Duration::SECOND)
+    }
+}
+
+/// Returns the inclusive bounds of a range.
+///
+/// # Panics
+/// Panics if the range is unbounded, as the API requires both bounds.
+fn inclusive_bounds<T: RangeValue>(range: &impl RangeBounds<T>) -> (T, T) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.successor(),
+        Bound::Unbounded => panic!("range must have a start bound"),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.predecessor(),
+        Bound::Unbounded => panic!("range must have an end bound"),
+    };
+    (start, end)
+}
+
+/// A field that a collection can be sorted by.
+pub trait SortField: Copy {
+    /// Returns the name of the field used in the query string.
+    fn as_str(&self) -> &'static str;
+
+    /// Sorts by this field in ascending order.
+    #[must_use]
+    fn asc(self) -> Sort<Self> {
+        Sort {
+            field: self,
+            descending: false,
+        }
+    }
+
+    /// Sorts by this field in descending order.
+    #[must_use]
+    fn desc(self) -> Sort<Self> {
+        Sort {
+            field: self,
+            descending: true,
+        }
+    }
+}
+
+/// A sort on a field, in ascending or descending order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Sort<F> {
+    pub field: F,
+    pub descending: bool,
+}
+
+impl<F: SortField> Display for Sort<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.descending {
+            write!(f, "-{}", self.field.as_str())
+        } else {
+            write!(f, "{}", self.field.as_str())
+        }
+    }
+}
+
+impl<F: SortField> From<F> for Sort<F> {
+    fn from(field: F) -> Self {
+        field.asc()
+    }
+}
+
+macro_rules! typed_options {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $sort:ident {
+            filter { $($filter:ident => $filter_key:literal: $filter_ty:ty),* $(,)? }
+            search { $($search:ident => $search_key:literal),* $(,)? }
+            range { $($range:ident => $range_key:literal: $range_ty:ty),* $(,)? }
+            sort { $($variant:ident => $sort_key:literal),* $(,)? }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Eq, PartialEq, Default)]
+        pub struct $name(CollectionOptions);
+
+        impl $name {
+            /// Creates a new empty set of options.
+            #[must_use]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            $(
+                #[doc = concat!("Filters by `", $filter_key, "`.")]
+                /// If the filter already exists, the value is appended to the existing values.
+                #[must_use]
+                pub fn $filter(self, value: $filter_ty) -> Self {
+                    Self(self.0.filter($filter_key, value.to_query_value()))
+                }
+            )*
+
+            $(
+                #[doc = concat!("Searches by `", $search_key, "`.")]
+                /// If the search already exists, the value is overwritten.
+                #[must_use]
+                pub fn $search(self, value: impl Into<CompactString>) -> Self {
+                    Self(self.0.search($search_key, value))
+                }
+            )*
+
+            $(
+                #[doc = concat!("Restricts `", $range_key, "` to the given range, e.g. `start..end`.")]
+                /// The API only supports inclusive ranges,
+                /// so an excluded end is replaced by the closest value before it.
+                /// If the range already exists, the value is overwritten.
+                ///
+                /// # Panics
+                /// Panics if the range has no start or no end, e.g. `start..`.
+                #[must_use]
+                pub fn $range(self, range: impl RangeBounds<$range_ty>) -> Self {
+                    let (start, end) = inclusive_bounds(&range);
+                    Self(self.0.range_str(
+                        $range_key,
+                        start.to_query_value(),
+                        end.to_query_value(),
+                    ))
+                }
+            )*
+
+            /// Adds a sort.
+            /// If a sort already exists, the value is appended as a secondary sort.
+            #[must_use]
+            pub fn sort_by(self, sort: impl Into<Sort<$sort>>) -> Self {
+                Self(self.0.sort(sort.into().to_compact_string()))
+            }
+
+            /// Sets the page number.
+            #[must_use]
+            pub fn page(self, page: u32) -> Self {
+                Self(self.0.page(page))
+            }
+
+            /// Sets the number of items per page.
+            #[must_use]
+            pub fn per_page(self, per_page: u32) -> Self {
+                Self(self.0.per_page(per_page))
+            }
+        }
+
+        impl From<$name> for CollectionOptions {
+            fn from(options: $name) -> Self {
+                options.0
+            }
+        }
+
+        #[doc = concat!("Fields that [`", stringify!($name), "`] can be sorted by.")]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        #[non_exhaustive]
+        pub enum $sort {
+            $($variant,)*
+        }
+
+        impl SortField for $sort {
+            fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $sort_key,)*
+                }
+            }
+        }
+    };
+}
+
+typed_options! {
+    /// Typed collection options for matches.
+    MatchOptions, MatchSort {
+        filter {
+            filter_begin_at => "begin_at": OffsetDateTime,
+            filter_detailed_stats => "detailed_stats": bool,
+            filter_draw => "draw": bool,
+            filter_end_at => "end_at": OffsetDateTime,
+            filter_forfeit => "forfeit": bool,
+            filter_id => "id": u64,
+            filter_league_id => "league_id": u64,
+            filter_match_type => "match_type": MatchType,
+            filter_name => "name": &str,
+            filter_number_of_games => "number_of_games": u32,
+            filter_opponent_id => "opponent_id": u64,
+            filter_scheduled_at => "scheduled_at": OffsetDateTime,
+            filter_serie_id => "serie_id": u64,
+            filter_slug => "slug": &str,
+            filter_status => "status": MatchStatus,
+            filter_tournament_id => "tournament_id": u64,
+            filter_videogame => "videogame": VideoGameSlug,
+            filter_winner_id => "winner_id": u64,
+        }
+        search {
+            search_name => "name",
+            search_slug => "slug",
+        }
+        range {
+            range_begin_at => "begin_at": OffsetDateTime,
+            range_end_at => "end_at": OffsetDateTime,
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+            range_number_of_games => "number_of_games": u32,
+            range_scheduled_at => "scheduled_at": OffsetDateTime,
+        }
+        sort {
+            BeginAt => "begin_at",
+            EndAt => "end_at",
+            Id => "id",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            NumberOfGames => "number_of_games",
+            ScheduledAt => "scheduled_at",
+            Slug => "slug",
+            Status => "status",
+            TournamentId => "tournament_id",
+        }
+    }
+}
+
+typed_options! {
+    /// Typed collection options for tournaments.
+    TournamentOptions, TournamentSort {
+        filter {
+            filter_begin_at => "begin_at": OffsetDateTime,
+            filter_detailed_stats => "detailed_stats": bool,
+            filter_end_at => "end_at": OffsetDateTime,
+            filter_has_bracket => "has_bracket": bool,
+            filter_id => "id": u64,
+            filter_league_id => "league_id": u64,
+            filter_live_supported => "live_supported": bool,
+            filter_name => "name": &str,
+            filter_serie_id => "serie_id": u64,
+            filter_slug => "slug": &str,
+            filter_tier => "tier": Tier,
+            filter_videogame => "videogame": VideoGameSlug,
+            filter_winner_id => "winner_id": u64,
+        }
+        search {
+            search_name => "name",
+            search_slug => "slug",
+        }
+        range {
+            range_begin_at => "begin_at": OffsetDateTime,
+            range_end_at => "end_at": OffsetDateTime,
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+        }
+        sort {
+            BeginAt => "begin_at",
+            EndAt => "end_at",
+            Id => "id",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            SerieId => "serie_id",
+            Slug => "slug",
+            Tier => "tier",
+        }
+    }
+}
+
+typed_options! {
+    /// Typed collection options for series.
+    SeriesOptions, SeriesSort {
+        filter {
+            filter_begin_at => "begin_at": OffsetDateTime,
+            filter_end_at => "end_at": OffsetDateTime,
+            filter_full_name => "full_name": &str,
+            filter_id => "id": u64,
+            filter_league_id => "league_id": u64,
+            filter_name => "name": &str,
+            filter_season => "season": &str,
+            filter_slug => "slug": &str,
+            filter_videogame => "videogame": VideoGameSlug,
+            filter_winner_id => "winner_id": u64,
+            filter_year => "year": u16,
+        }
+        search {
+            search_full_name => "full_name",
+            search_name => "name",
+            search_season => "season",
+            search_slug => "slug",
+        }
+        range {
+            range_begin_at => "begin_at": OffsetDateTime,
+            range_end_at => "end_at": OffsetDateTime,
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+            range_year => "year": u16,
+        }
+        sort {
+            BeginAt => "begin_at",
+            EndAt => "end_at",
+            FullName => "full_name",
+            Id => "id",
+            LeagueId => "league_id",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            Season => "season",
+            Slug => "slug",
+            Year => "year",
+        }
+    }
+}
+
+typed_options! {
+    /// Typed collection options for leagues.
+    LeagueOptions, LeagueSort {
+        filter {
+            filter_id => "id": u64,
+            filter_name => "name": &str,
+            filter_slug => "slug": &str,
+            filter_url => "url": &str,
+            filter_videogame => "videogame": VideoGameSlug,
+        }
+        search {
+            search_name => "name",
+            search_slug => "slug",
+            search_url => "url",
+        }
+        range {
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+        }
+        sort {
+            Id => "id",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            Slug => "slug",
+            Url => "url",
+        }
+    }
+}
+
+typed_options! {
+    /// Typed collection options for players.
+    PlayerOptions, PlayerSort {
+        filter {
+            filter_active => "active": bool,
+            filter_age => "age": u32,
+            filter_first_name => "first_name": &str,
+            filter_id => "id": u64,
+            filter_last_name => "last_name": &str,
+            filter_name => "name": &str,
+            filter_nationality => "nationality": &str,
+            filter_role => "role": &str,
+            filter_slug => "slug": &str,
+            filter_videogame => "videogame": VideoGameSlug,
+        }
+        search {
+            search_first_name => "first_name",
+            search_last_name => "last_name",
+            search_name => "name",
+            search_nationality => "nationality",
+            search_role => "role",
+            search_slug => "slug",
+        }
+        range {
+            range_age => "age": u32,
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+        }
+        sort {
+            Age => "age",
+            FirstName => "first_name",
+            Id => "id",
+            LastName => "last_name",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            Nationality => "nationality",
+            Role => "role",
+            Slug => "slug",
+        }
+    }
+}
+
+typed_options! {
+    /// Typed collection options for teams.
+    TeamOptions, TeamSort {
+        filter {
+            filter_acronym => "acronym": &str,
+            filter_id => "id": u64,
+            filter_location => "location": &str,
+            filter_name => "name": &str,
+            filter_slug => "slug": &str,
+            filter_videogame => "videogame": VideoGameSlug,
+        }
+        search {
+            search_acronym => "acronym",
+            search_location => "location",
+            search_name => "name",
+            search_slug => "slug",
+        }
+        range {
+            range_id => "id": u64,
+            range_modified_at => "modified_at": OffsetDateTime,
+        }
+        sort {
+            Acronym => "acronym",
+            Id => "id",
+            Location => "location",
+            ModifiedAt => "modified_at",
+            Name => "name",
+            Slug => "slug",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_match_options() {
+        let options: CollectionOptions = MatchOptions::new()
+            .filter_status(MatchStatus::Running)
+            .filter_status(MatchStatus::NotStarted)
+            .filter_videogame(VideoGameSlug::LeagueOfLegends)
+            .search_name("T1")
+            .range_begin_at(datetime!(2024-01-01 0:00 UTC)..=datetime!(2024-02-01 0:00 +1))
+            .sort_by(MatchSort::BeginAt.desc())
+            .sort_by(MatchSort::Id)
+            .page(2)
+            .per_page(50)
+            .into();
+
+        let expected = CollectionOptions::new()
+            .filter("status", "running")
+            .filter("status", "not_started")
+            .filter("videogame", "league-of-legends")
+            .search("name", "T1")
+            .range_str("begin_at", "2024-01-01T00:00:00Z", "2024-01-31T23:00:00Z")
+            .sort("-begin_at")
+            .sort("id")
+            .page(2)
+            .per_page(50);
+
+        assert_eq!(options, expected);
+    }
+
+    #[test]
+    fn test_tournament_options() {
+        let options: CollectionOptions = TournamentOptions::new()
+            .filter_tier(Tier::S)
+            .filter_has_bracket(true)
+            .range_id(1..=100)
+            .sort_by(TournamentSort::Tier.asc())
+            .into();
+
+        let expected = CollectionOptions::new()
+            .filter("tier", "s")
+            .filter("has_bracket", "true")
+            .range("id", 1, 100)
+            .sort("tier");
+
+        assert_eq!(options, expected);
+    }
+
+    #[test]
+    fn test_half_open_range() {
+        let options: CollectionOptions = MatchOptions::new()
+            .range_id(1..101)
+            .range_begin_at(datetime!(2024-01-01 0:00 UTC)..datetime!(2024-02-01 0:00 UTC))
+            .into();
+
+        let expected = CollectionOptions::new().range("id", 1, 100).range_str(
+            "begin_at",
+            "2024-01-01T00:00:00Z",
+            "2024-01-31T23:59:59Z",
+        );
+
+        assert_eq!(options, expected);
+    }
+
+    #[test]
+    #[should_panic = "range must have an end bound"]
+    fn test_unbounded_range() {
+        let _ = TournamentOptions::new().range_id(1..);
+    }
+}
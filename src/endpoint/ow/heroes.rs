@@ -0,0 +1,4 @@
+use crate::model::ow::hero::Hero;
+
+crate::endpoint::list_endpoint!(ListHeroes("/ow/heroes") => Hero);
+crate::endpoint::get_endpoint!(GetHero("/ow/heroes") => Hero);
@@ -0,0 +1,4 @@
+use crate::model::ow::map::Map;
+
+crate::endpoint::list_endpoint!(ListMaps("/ow/maps") => Map);
+crate::endpoint::get_endpoint!(GetMap("/ow/maps") => Map);
@@ -0,0 +1,6 @@
+//! # Overwatch Endpoints
+
+pub mod heroes;
+pub mod maps;
+
+crate::endpoint::game_endpoints!("ow");
@@ -0,0 +1,4 @@
+use crate::model::pubg::map::Map;
+
+crate::endpoint::list_endpoint!(ListMaps("/pubg/maps") => Map);
+crate::endpoint::get_endpoint!(GetMap("/pubg/maps") => Map);
@@ -0,0 +1,5 @@
+//! # PUBG Endpoints
+
+pub mod maps;
+
+crate::endpoint::game_endpoints!("pubg");
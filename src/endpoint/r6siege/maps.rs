@@ -0,0 +1,4 @@
+use crate::model::r6siege::map::Map;
+
+crate::endpoint::list_endpoint!(ListMaps("/r6siege/maps") => Map);
+crate::endpoint::get_endpoint!(GetMap("/r6siege/maps") => Map);
@@ -0,0 +1,6 @@
+//! # Rainbow Six Siege Endpoints
+
+pub mod maps;
+pub mod operators;
+
+crate::endpoint::game_endpoints!("r6siege");
@@ -0,0 +1,4 @@
+use crate::model::r6siege::operator::Operator;
+
+crate::endpoint::list_endpoint!(ListOperators("/r6siege/operators") => Operator);
+crate::endpoint::get_endpoint!(GetOperator("/r6siege/operators") => Operator);
@@ -1,3 +0,0 @@
-//! Rocket League endpoints.
-
-crate::endpoint::game_endpoints!("rl");
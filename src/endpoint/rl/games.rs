@@ -0,0 +1 @@
+crate::endpoint::games_endpoints!("rl" => crate::model::rl::game::Game);
@@ -0,0 +1,5 @@
+//! # Rocket League Endpoints
+
+pub mod games;
+
+crate::endpoint::game_endpoints!("rl");
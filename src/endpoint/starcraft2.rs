@@ -0,0 +1,3 @@
+//! # StarCraft 2 Endpoints
+
+crate::endpoint::game_endpoints!("starcraft-2");
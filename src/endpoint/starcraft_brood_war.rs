@@ -0,0 +1,3 @@
+//! # StarCraft Brood War Endpoints
+
+crate::endpoint::game_endpoints!("starcraft-brood-war");
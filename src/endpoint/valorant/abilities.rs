@@ -0,0 +1,4 @@
+use crate::model::valorant::ability::Ability;
+
+crate::endpoint::list_endpoint!(ListAbilities("/valorant/abilities") => Ability);
+crate::endpoint::get_endpoint!(GetAbility("/valorant/abilities") => Ability);
@@ -0,0 +1,4 @@
+use crate::model::valorant::agent::Agent;
+
+crate::endpoint::list_endpoint!(ListAgents("/valorant/agents") => Agent);
+crate::endpoint::get_endpoint!(GetAgent("/valorant/agents") => Agent);
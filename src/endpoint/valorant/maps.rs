@@ -0,0 +1,4 @@
+use crate::model::valorant::map::Map;
+
+crate::endpoint::list_endpoint!(ListMaps("/valorant/maps") => Map);
+crate::endpoint::get_endpoint!(GetMap("/valorant/maps") => Map);
@@ -0,0 +1,8 @@
+//! # Valorant Endpoints
+
+pub mod abilities;
+pub mod agents;
+pub mod maps;
+pub mod weapons;
+
+crate::endpoint::game_endpoints!("valorant");
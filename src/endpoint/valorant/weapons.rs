@@ -0,0 +1,4 @@
+use crate::model::valorant::weapon::Weapon;
+
+crate::endpoint::list_endpoint!(ListWeapons("/valorant/weapons") => Weapon);
+crate::endpoint::get_endpoint!(GetWeapon("/valorant/weapons") => Weapon);
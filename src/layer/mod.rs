@@ -0,0 +1,10 @@
+//! [`tower`] layers that can be applied to a [`ClientTransport`](crate::ClientTransport).
+//!
+//! The layered service is itself a [`ClientTransport`](crate::ClientTransport),
+//! so it can be passed directly to [`Client::new`](crate::Client::new).
+
+mod retry;
+mod throttle;
+
+pub use retry::RetryPolicy;
+pub use throttle::{Throttle, ThrottleLayer};
@@ -0,0 +1,149 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{Error, Request, Response, StatusCode};
+use tokio::time::Sleep;
+use tower::retry::Policy;
+
+use crate::endpoint::retry_after;
+
+/// A [`Policy`] that retries transient failures with exponential backoff.
+///
+/// Requests are retried when:
+/// - the API responds with `429 Too Many Requests`,
+///   waiting for the duration of the `Retry-After` header if present.
+/// - the API responds with a `5xx` server error.
+/// - the connection fails or times out.
+///
+/// Other `4xx` client errors are never retried.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pandascore::{layer::RetryPolicy, Client};
+/// use tower::{retry::RetryLayer, Layer};
+///
+/// let policy = RetryPolicy::builder().max_retries(5).build();
+/// let transport = RetryLayer::new(policy).layer(reqwest::Client::new());
+/// let client = Client::new(transport, "token").unwrap();
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, bon::Builder)]
+pub struct RetryPolicy {
+    /// Maximum number of retries for a single request.
+    #[builder(default = 3)]
+    max_retries: u32,
+    /// Delay before the first retry, doubled on each subsequent retry.
+    #[builder(default = Duration::from_millis(500))]
+    base_delay: Duration,
+    /// Upper bound on the backoff delay.
+    #[builder(default = Duration::from_secs(30))]
+    max_delay: Duration,
+    /// Whether to randomize the backoff delay to avoid synchronized retries.
+    #[builder(default = true)]
+    jitter: bool,
+    /// Number of retries already performed for the current request.
+    #[builder(skip)]
+    attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the backoff delay for the current attempt.
+    fn backoff(&self) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(self.attempts))
+            .min(self.max_delay);
+
+        if self.jitter {
+            // "Equal jitter": half of the delay is fixed, the other half is random.
+            let half = delay / 2;
+            let random = RandomState::new().build_hasher().finish();
+            #[allow(clippy::cast_precision_loss)]
+            let factor = random as f64 / u64::MAX as f64;
+            half + half.mul_f64(factor)
+        } else {
+            delay
+        }
+    }
+
+    /// Returns the delay before retrying, or `None` if the result shouldn't be retried.
+    fn delay(&self, result: &Result<Response, Error>) -> Option<Duration> {
+        match result {
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                Some(retry_after(response.headers()).unwrap_or_else(|| self.backoff()))
+            }
+            Ok(response) if response.status().is_server_error() => Some(self.backoff()),
+            Err(error) if error.is_connect() || error.is_timeout() => Some(self.backoff()),
+            Ok(_) | Err(_) => None,
+        }
+    }
+}
+
+impl Policy<Request, Response, Error> for RetryPolicy {
+    type Future = Sleep;
+
+    fn retry(
+        &mut self,
+        _req: &mut Request,
+        result: &mut Result<Response, Error>,
+    ) -> Option<Self::Future> {
+        if self.attempts >= self.max_retries {
+            return None;
+        }
+        let delay = self.delay(result)?;
+        self.attempts += 1;
+        Some(tokio::time::sleep(delay))
+    }
+
+    fn clone_request(&mut self, req: &Request) -> Option<Request> {
+        req.try_clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let mut policy = RetryPolicy::builder()
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .jitter(false)
+            .build();
+
+        let delays = (0..5)
+            .map(|attempt| {
+                policy.attempts = attempt;
+                policy.backoff()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 5, 5].map(Duration::from_secs).to_vec(),
+            "backoff should double and be capped at max_delay"
+        );
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let policy = RetryPolicy::builder()
+            .base_delay(Duration::from_secs(2))
+            .build();
+
+        for _ in 0..100 {
+            let delay = policy.backoff();
+            assert!(delay >= Duration::from_secs(1));
+            assert!(delay <= Duration::from_secs(2));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use reqwest::{Error, Request, Response};
+use tokio::time::Instant;
+use tower::{Layer, Service};
+
+use crate::{endpoint::rate_limit_remaining, ClientTransport};
+
+/// A [`Layer`] that throttles requests to stay under a rate limit budget.
+///
+/// Requests are spaced evenly so that at most `budget` requests are sent per `period`.
+/// Additionally, if a response reports that no requests remain in the current window
+/// (using the `X-Rate-Limit-Remaining` header),
+/// the next request is delayed by a full `period`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pandascore::{layer::ThrottleLayer, Client};
+/// use tower::Layer;
+///
+/// // Free tier is limited to 1000 requests per hour.
+/// let transport = ThrottleLayer::hourly(1000).layer(reqwest::Client::new());
+/// let client = Client::new(transport, "token").unwrap();
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ThrottleLayer {
+    budget: u32,
+    period: Duration,
+}
+
+impl ThrottleLayer {
+    /// Creates a new layer allowing at most `budget` requests per `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    #[must_use]
+    pub fn new(budget: u32, period: Duration) -> Self {
+        assert!(budget > 0, "budget must be non-zero");
+        Self { budget, period }
+    }
+
+    /// Creates a new layer allowing at most `budget` requests per hour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    #[must_use]
+    pub fn hourly(budget: u32) -> Self {
+        Self::new(budget, Duration::from_secs(60 * 60))
+    }
+}
+
+impl<S> Layer<S> for ThrottleLayer {
+    type Service = Throttle<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Throttle {
+            inner,
+            interval: self.period / self.budget,
+            period: self.period,
+            state: Arc::default(),
+        }
+    }
+}
+
+/// A service that throttles requests to stay under a rate limit budget.
+///
+/// See [`ThrottleLayer`] for details.
+/// Clones of this service share the same budget.
+#[derive(Debug, Clone)]
+pub struct Throttle<S> {
+    inner: S,
+    interval: Duration,
+    period: Duration,
+    state: Arc<Mutex<ThrottleState>>,
+}
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    /// The earliest time the next request can be sent.
+    next: Option<Instant>,
+}
+
+impl<S> Throttle<S> {
+    /// Reserves a slot for a request, returning the time it can be sent.
+    fn reserve(&self) -> Instant {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let slot = state.next.map_or(now, |next| next.max(now));
+        state.next = Some(slot + self.interval);
+        slot
+    }
+}
+
+impl<S> Service<Request> for Throttle<S>
+where
+    S: ClientTransport + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let slot = self.reserve();
+        let period = self.period;
+        let state = Arc::clone(&self.state);
+
+        Box::pin(async move {
+            tokio::time::sleep_until(slot).await;
+            let response = inner.call(req).await?;
+
+            if rate_limit_remaining(response.headers()) == Some(0) {
+                // Budget exhausted, wait for the window to reset.
+                let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+                let reset = Instant::now() + period;
+                state.next = Some(state.next.map_or(reset, |next| next.max(reset)));
+            }
+
+            Ok(response)
+        })
+    }
+}
@@ -3,44 +3,50 @@
 //! Currently **only** supports the free tier of the API.
 //!
 //! ## Features
-//! - [ ] "All Video Games" endpoints
-//!     - [ ] Incidents
+//! - [x] "All Video Games" endpoints
+//!     - [x] Incidents
 //!     - [x] Leagues
-//!     - [ ] Lives
+//!     - [x] Lives
 //!     - [x] Matches
 //!     - [x] Players
 //!     - [x] Series
 //!     - [x] Teams
 //!     - [x] Tournaments
-//!     - [ ] Video Games
+//!     - [x] Video Games
 //! - [ ] "League of Legends" endpoints
 //!     - [x] Champions
-//!     - [ ] ~Games~
+//!     - [x] Games
 //!     - [x] Items
 //!     - [x] Leagues
 //!     - [ ] ~Mastery~
-//!     - [ ] ~Stats~
+//!     - [ ] ~Champion stats~ (out of scope, see [`endpoint::lol::stats`])
 //!     - [x] Matches
-//!     - [ ] ~Stats~
+//!     - [x] Player and team stats
 //!     - [x] Players
 //!     - [x] Series
 //!     - [x] Teams
 //!     - [x] Spells
 //!     - [x] Tournaments
-//! - [ ] "Call of Duty" endpoints
-//! - [ ] "Counter Strike" endpoints
-//! - [ ] "Dota 2" endpoints
-//! - [ ] "EA Sports FC" endpoints
-//! - [ ] "LOL Wild Rift" endpoints
-//! - [ ] "Mobile Legends: Bang Bang" endpoints
-//! - [ ] "OverWatch" endpoints
-//! - [ ] "PUBG" endpoints
-//! - [ ] "Rainbow Six Siege" endpoints
+//! - [x] "Call of Duty" endpoints
+//! - [x] "Counter Strike" endpoints
+//! - [x] "Dota 2" endpoints
+//! - [x] "EA Sports FC" endpoints
+//! - [x] "LOL Wild Rift" endpoints
+//! - [x] "Mobile Legends: Bang Bang" endpoints
+//! - [x] "OverWatch" endpoints
+//! - [x] "PUBG" endpoints
+//! - [x] "Rainbow Six Siege" endpoints
 //! - [x] "Rocket League" endpoints
-//! - [ ] "Valorant" endpoints
-//! - [ ] "King of Glory" endpoints
-//! - [ ] "StarCraft 2" endpoints
-//! - [ ] "StarCraft Brood War" endpoints
+//! - [x] "Valorant" endpoints
+//! - [x] "King of Glory" endpoints
+//! - [x] "StarCraft 2" endpoints
+//! - [x] "StarCraft Brood War" endpoints
+//!
+//! ## Cargo features
+//! The client itself doesn't depend on an async runtime.
+//! The following optional features use [tokio](https://tokio.rs) and are disabled by default:
+//! - `layers`: the `layer` module, with throttling and retry [`tower`] layers.
+//! - `sync`: the `sync` module, with incremental synchronization driven by incidents.
 //!
 //! ## Examples
 //! To search for a league by name:
@@ -54,6 +60,10 @@
 
 mod client;
 pub mod endpoint;
+#[cfg(feature = "layers")]
+pub mod layer;
 pub mod model;
+#[cfg(feature = "sync")]
+pub mod sync;
 
 pub use client::{Client, ClientTransport};
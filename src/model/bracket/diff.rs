@@ -0,0 +1,101 @@
+use crate::model::{bracket::TournamentBracketMatch, matches::CompactMatchOpponent, Winner};
+
+/// The differences between two versions of a bracket,
+/// as returned by [`TournamentBracket::diff`](super::TournamentBracket::diff).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct BracketDiff {
+    /// IDs of the matches only in the new bracket.
+    pub added: Vec<u64>,
+    /// IDs of the matches only in the old bracket.
+    pub removed: Vec<u64>,
+    /// Matches in both brackets that changed.
+    pub changed: Vec<MatchDiff>,
+}
+
+impl BracketDiff {
+    /// Returns `true` if the brackets are the same.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The changes to a match between two versions of a bracket.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct MatchDiff {
+    pub match_id: u64,
+    /// What changed, in the order of [`MatchChange`] variants.
+    pub changes: Vec<MatchChange>,
+}
+
+impl MatchDiff {
+    /// Compares two versions of the same match,
+    /// returning `None` if none of the tracked fields changed.
+    pub(super) fn between(
+        old: &TournamentBracketMatch,
+        new: &TournamentBracketMatch,
+    ) -> Option<Self> {
+        let mut changes = Vec::new();
+        if old.inner.status != new.inner.status {
+            changes.push(MatchChange::Status);
+        }
+        if winner_key(old.inner.winner.as_ref()) != winner_key(new.inner.winner.as_ref()) {
+            changes.push(MatchChange::Winner);
+        }
+        if old.results != new.results {
+            changes.push(MatchChange::Score);
+        }
+        if !old
+            .opponents
+            .iter()
+            .map(opponent_key)
+            .eq(new.opponents.iter().map(opponent_key))
+        {
+            changes.push(MatchChange::Opponents);
+        }
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(Self {
+                match_id: new.inner.id,
+                changes,
+            })
+        }
+    }
+}
+
+/// A field of a match that changed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MatchChange {
+    /// The status changed, e.g. the match started or finished.
+    Status,
+    /// The winner was set, removed or changed.
+    Winner,
+    /// The results changed.
+    Score,
+    /// The opponents were set or changed.
+    Opponents,
+}
+
+/// Winners are compared by type and ID,
+/// as the team or player object is not always returned.
+const fn winner_key(winner: Option<&Winner>) -> Option<(bool, Option<u64>)> {
+    match winner {
+        Some(Winner::Team { id, .. }) => Some((true, *id)),
+        Some(Winner::Player { id, .. }) => Some((false, *id)),
+        None => None,
+    }
+}
+
+/// Opponents are compared by type and ID,
+/// so changes to the teams or players themselves are ignored.
+const fn opponent_key(opponent: &CompactMatchOpponent) -> (bool, u64) {
+    match opponent {
+        CompactMatchOpponent::Team(team) => (true, team.id),
+        CompactMatchOpponent::Player(player) => (false, player.id),
+    }
+}
@@ -1,16 +1,19 @@
 use std::fmt::{Display, Formatter};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::model::matches::{CompactMatch, CompactMatchOpponent, MatchResult};
+use crate::model::{
+    game::CompactGame,
+    matches::{CompactMatch, CompactMatchOpponent, MatchResult},
+};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TournamentBracketMatch {
     #[serde(flatten)]
     pub inner: CompactMatch,
 
-    // pub games: Vec<BracketGame>,
+    pub games: Vec<CompactGame>,
     pub opponents: Vec<CompactMatchOpponent>,
     pub previous_matches: Vec<TournamentPreviousMatch>,
     pub results: Vec<MatchResult>,
@@ -22,14 +25,14 @@ impl Display for TournamentBracketMatch {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct TournamentPreviousMatch {
     pub match_id: u64,
     #[serde(rename = "type")]
     pub r#type: PreviousMatchType,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PreviousMatchType {
     Winner,
@@ -1,56 +1,454 @@
+mod diff;
 mod matches;
-use std::collections::HashMap;
+mod round;
+mod validation;
 
+use std::collections::{hash_map::Entry, HashMap};
+
+pub use diff::{BracketDiff, MatchChange, MatchDiff};
 pub use matches::{PreviousMatchType, TournamentBracketMatch, TournamentPreviousMatch};
-use petgraph::Graph;
+use petgraph::{graph::NodeIndex, visit::EdgeRef, Direction, Graph};
+pub use round::{BracketStage, Round, RoundLabel};
+use serde::{Deserialize, Serialize, Serializer};
+pub use validation::{BracketIssue, BracketValidationError};
 
-#[derive(Debug, Clone)]
+/// A tournament bracket, as a graph of matches.
+///
+/// Edges go from a match to the matches its winner or loser advances to.
+/// The bracket is (de)serialized as the list of its matches, like the API returns it.
+/// Deserializing ignores any problem with the matches, like [`TournamentBracket::new`]
+/// and [`GetTournamentBracket`](crate::endpoint::all::tournament::GetTournamentBracket),
+/// so every bracket can be serialized and deserialized back.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "Vec<TournamentBracketMatch>")]
 pub struct TournamentBracket {
     tree: Graph<TournamentBracketMatch, PreviousMatchType>,
+    nodes: HashMap<u64, NodeIndex>,
+    /// The round of every match, indexed by node.
+    rounds: Vec<Round>,
 }
 
 impl TournamentBracket {
     #[must_use]
     #[inline]
     pub fn new_empty() -> Self {
-        Self { tree: Graph::new() }
+        Self {
+            tree: Graph::new(),
+            nodes: HashMap::new(),
+            rounds: Vec::new(),
+        }
     }
 
+    /// Builds a bracket from its matches, ignoring any problem with them.
+    ///
+    /// Duplicate matches and unknown previous matches are skipped.
+    /// Use [`TournamentBracket::try_new`] to find out about them instead.
     #[must_use]
-    pub fn new(mut matches: Vec<TournamentBracketMatch>) -> Self {
-        let mut graph = Graph::with_capacity(matches.len(), matches.len());
+    pub fn new(matches: Vec<TournamentBracketMatch>) -> Self {
+        Self::build(matches).0
+    }
+
+    /// Builds a bracket from its matches, checking that they form a valid bracket.
+    ///
+    /// # Errors
+    /// Returns a report of every [`BracketIssue`] found,
+    /// if any match is duplicated, refers to an unknown previous match,
+    /// is scheduled before one of its previous matches, or is part of a cycle.
+    pub fn try_new(matches: Vec<TournamentBracketMatch>) -> Result<Self, BracketValidationError> {
+        let (bracket, issues) = Self::build(matches);
+        if issues.is_empty() {
+            Ok(bracket)
+        } else {
+            Err(BracketValidationError { issues })
+        }
+    }
 
-        // This is OK b/c node indices are stable across insertions
-        let mut match_map = HashMap::new();
+    fn build(mut matches: Vec<TournamentBracketMatch>) -> (Self, Vec<BracketIssue>) {
+        let mut issues = Vec::new();
+        let mut graph = Graph::with_capacity(matches.len(), matches.len());
+        let mut nodes = HashMap::with_capacity(matches.len());
 
-        // Sort matches from oldest
-        matches.sort_unstable_by_key(|m| m.inner.scheduled_at);
+        // Sort matches from oldest, so they are iterated in a natural order
+        matches.sort_by_key(|m| m.inner.scheduled_at);
 
         for m in matches {
-            // Add node to graph
-            let id = m.inner.id;
-            let node = graph.add_node(m);
-            match_map.insert(id, node);
-
-            // Look at previous matches and add edges
-            let prev_matches = graph[node].previous_matches.clone();
-            for prev_match in prev_matches {
-                let prev_node = match_map[&prev_match.match_id];
-                graph.add_edge(prev_node, node, prev_match.r#type);
+            match nodes.entry(m.inner.id) {
+                Entry::Occupied(_) => issues.push(BracketIssue::DuplicateMatch {
+                    match_id: m.inner.id,
+                }),
+                Entry::Vacant(entry) => {
+                    entry.insert(graph.add_node(m));
+                }
+            }
+        }
+
+        // Edges are added once every match is known,
+        // so they don't depend on the matches being scheduled in order
+        let edges = graph
+            .node_indices()
+            .flat_map(|node| {
+                graph[node]
+                    .previous_matches
+                    .iter()
+                    .map(move |prev_match| (node, *prev_match))
+            })
+            .collect::<Vec<_>>();
+        for (node, prev_match) in edges {
+            let match_id = graph[node].inner.id;
+            let Some(&prev_node) = nodes.get(&prev_match.match_id) else {
+                issues.push(BracketIssue::UnknownPreviousMatch {
+                    match_id,
+                    previous_match_id: prev_match.match_id,
+                });
+                continue;
+            };
+
+            if let (Some(scheduled_at), Some(prev_scheduled_at)) = (
+                graph[node].inner.scheduled_at,
+                graph[prev_node].inner.scheduled_at,
+            ) {
+                if scheduled_at < prev_scheduled_at {
+                    issues.push(BracketIssue::ScheduledBeforePreviousMatch {
+                        match_id,
+                        previous_match_id: prev_match.match_id,
+                    });
+                }
+            }
+            graph.add_edge(prev_node, node, prev_match.r#type);
+        }
+
+        for component in petgraph::algo::tarjan_scc(&graph) {
+            if component.len() > 1 || graph.contains_edge(component[0], component[0]) {
+                let mut match_ids = component
+                    .into_iter()
+                    .map(|node| graph[node].inner.id)
+                    .collect::<Vec<_>>();
+                match_ids.sort_unstable();
+                issues.push(BracketIssue::Cycle { match_ids });
+            }
+        }
+
+        let mut bracket = Self {
+            tree: graph,
+            nodes,
+            rounds: Vec::new(),
+        };
+        bracket.rounds = bracket.compute_rounds();
+        (bracket, issues)
+    }
+
+    /// Returns the match with the given ID.
+    #[must_use]
+    pub fn get(&self, match_id: u64) -> Option<&TournamentBracketMatch> {
+        self.nodes.get(&match_id).map(|&node| &self.tree[node])
+    }
+
+    /// Adds a match to the bracket, linking it to its previous and next matches.
+    ///
+    /// Previous matches that are not in the bracket yet are linked once they are added.
+    ///
+    /// # Errors
+    /// Returns an error and leaves the bracket unchanged
+    /// if a match with the same ID is already in the bracket,
+    /// or if the match would create a cycle.
+    ///
+    /// These are the only checks: previous matches may be added later,
+    /// and schedules change as the tournament goes on,
+    /// so [`BracketIssue::UnknownPreviousMatch`] and
+    /// [`BracketIssue::ScheduledBeforePreviousMatch`] are never returned.
+    /// Rebuild the bracket with [`TournamentBracket::try_new`] to check for them.
+    pub fn add_match(&mut self, m: TournamentBracketMatch) -> Result<(), BracketValidationError> {
+        let match_id = m.inner.id;
+        if self.nodes.contains_key(&match_id) {
+            return Err(BracketIssue::DuplicateMatch { match_id }.into());
+        }
+
+        let node = self.tree.add_node(m);
+        self.nodes.insert(match_id, node);
+        self.link_previous_matches(node);
+
+        // Link the matches that were added before this one
+        let next_matches = self
+            .tree
+            .node_indices()
+            .filter(|&next| next != node)
+            .flat_map(|next| {
+                self.tree[next]
+                    .previous_matches
+                    .iter()
+                    .filter(|prev_match| prev_match.match_id == match_id)
+                    .map(move |prev_match| (next, prev_match.r#type))
+            })
+            .collect::<Vec<_>>();
+        for (next, r#type) in next_matches {
+            self.tree.add_edge(node, next, r#type);
+        }
+
+        if let Some(issue) = self.find_cycle(node) {
+            // The node is the last one, so removing it doesn't move other nodes
+            self.tree.remove_node(node);
+            self.nodes.remove(&match_id);
+            return Err(issue.into());
+        }
+        self.rounds = self.compute_rounds();
+        Ok(())
+    }
+
+    /// Replaces the match with the same ID, returning the previous version.
+    ///
+    /// Links to previous matches are updated if they changed.
+    ///
+    /// # Errors
+    /// Returns an error and leaves the bracket unchanged
+    /// if the match is not in the bracket,
+    /// or if its new previous matches would create a cycle.
+    /// Like [`TournamentBracket::add_match`], nothing else is checked.
+    pub fn update_match(
+        &mut self,
+        m: TournamentBracketMatch,
+    ) -> Result<TournamentBracketMatch, BracketValidationError> {
+        let match_id = m.inner.id;
+        let Some(&node) = self.nodes.get(&match_id) else {
+            return Err(BracketIssue::UnknownMatch { match_id }.into());
+        };
+
+        let relink = m.previous_matches != self.tree[node].previous_matches;
+        let old = std::mem::replace(&mut self.tree[node], m);
+        if relink {
+            self.unlink_previous_matches(node);
+            self.link_previous_matches(node);
+
+            if let Some(issue) = self.find_cycle(node) {
+                self.tree[node] = old;
+                self.unlink_previous_matches(node);
+                self.link_previous_matches(node);
+                return Err(issue.into());
             }
+            self.rounds = self.compute_rounds();
         }
+        Ok(old)
+    }
 
-        Self { tree: graph }
+    /// Compares the bracket with a newer version of it,
+    /// e.g. to find out what changed between two calls to
+    /// [`GetTournamentBracket`](crate::endpoint::all::tournament::GetTournamentBracket).
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> BracketDiff {
+        let mut diff = BracketDiff::default();
+        for old in self.tree.node_weights() {
+            match other.get(old.inner.id) {
+                Some(new) => diff.changed.extend(MatchDiff::between(old, new)),
+                None => diff.removed.push(old.inner.id),
+            }
+        }
+        diff.added = other
+            .tree
+            .node_weights()
+            .map(|new| new.inner.id)
+            .filter(|id| !self.nodes.contains_key(id))
+            .collect();
+        diff
     }
 
-    // pub fn add_match(&mut self, m: TournamentBracketMatch) {
-    //     todo!()
-    // }
+    /// Returns the round of the match with the given ID.
+    #[must_use]
+    pub fn round(&self, match_id: u64) -> Option<Round> {
+        let node = *self.nodes.get(&match_id)?;
+        Some(self.rounds[node.index()])
+    }
+
+    /// Returns the rounds of the bracket, from the first round of the upper bracket
+    /// to the grand final, followed by the third place decider.
+    pub fn rounds(&self) -> impl Iterator<Item = Round> {
+        let mut rounds = self.rounds.clone();
+        rounds.sort_unstable();
+        rounds.dedup();
+        rounds.into_iter()
+    }
+
+    /// Returns the matches of the round with the given stage and number.
+    pub fn matches_in_round(
+        &self,
+        stage: BracketStage,
+        number: u32,
+    ) -> impl Iterator<Item = &TournamentBracketMatch> {
+        self.tree
+            .node_weights()
+            .zip(&self.rounds)
+            .filter(move |(_, r)| r.stage == stage && r.number == number)
+            .map(|(m, _)| m)
+    }
+
+    /// Computes the round of every match, indexed by node.
+    ///
+    /// A match is in the lower bracket if it is reached by losing a match,
+    /// or by winning a lower bracket match.
+    /// A match reached by winning both an upper and a lower bracket match is a grand final.
+    /// Its round number is its depth within its stage.
+    ///
+    /// A match only reached by losing matches, and leading to no other match,
+    /// is a third place decider if it is the only one, the bracket has no lower bracket,
+    /// and it is played by the losers of the upper bracket semifinals.
+    /// Otherwise it is the last match known so far of a lower bracket.
+    fn compute_rounds(&self) -> Vec<Round> {
+        let mut stages = vec![BracketStage::Upper; self.tree.node_count()];
+        let mut numbers = vec![1; self.tree.node_count()];
+
+        // Cycles are reported when building the bracket, fall back to insertion order
+        let order = petgraph::algo::toposort(&self.tree, None)
+            .unwrap_or_else(|_| self.tree.node_indices().collect());
+        for node in order {
+            let (mut lost, mut won_upper, mut won_lower, mut won_grand_final) =
+                (false, false, false, false);
+            for edge in self.tree.edges_directed(node, Direction::Incoming) {
+                match (edge.weight(), stages[edge.source().index()]) {
+                    (PreviousMatchType::Loser, _) => lost = true,
+                    (PreviousMatchType::Winner, BracketStage::Upper | BracketStage::ThirdPlace) => {
+                        won_upper = true;
+                    }
+                    (PreviousMatchType::Winner, BracketStage::Lower) => won_lower = true,
+                    (PreviousMatchType::Winner, BracketStage::GrandFinal) => {
+                        won_grand_final = true;
+                    }
+                }
+            }
+            let stage = if won_grand_final || (won_upper && won_lower) {
+                BracketStage::GrandFinal
+            } else if lost && !won_upper && !won_lower && self.is_last_match(node) {
+                BracketStage::ThirdPlace
+            } else if lost || won_lower {
+                BracketStage::Lower
+            } else {
+                BracketStage::Upper
+            };
+
+            stages[node.index()] = stage;
+            numbers[node.index()] = self.round_number(node, &stages, &numbers);
+        }
+
+        let third_places = self
+            .tree
+            .node_indices()
+            .filter(|node| stages[node.index()] == BracketStage::ThirdPlace)
+            .collect::<Vec<_>>();
+        let rounds_in = |stages: &[BracketStage], numbers: &[u32], stage| {
+            stages
+                .iter()
+                .zip(numbers)
+                .filter(|(s, _)| **s == stage)
+                .map(|(_, n)| *n)
+                .max()
+                .unwrap_or(1)
+        };
+        let upper_rounds = rounds_in(&stages, &numbers, BracketStage::Upper);
+        let is_third_place = match third_places[..] {
+            [node] => {
+                !stages.contains(&BracketStage::Lower)
+                    && self
+                        .tree
+                        .neighbors_directed(node, Direction::Incoming)
+                        .all(|prev| {
+                            stages[prev.index()] == BracketStage::Upper
+                                && numbers[prev.index()] + 1 == upper_rounds
+                        })
+            }
+            _ => false,
+        };
+        if !is_third_place {
+            // No match follows a third place decider, so other rounds are unchanged
+            for node in third_places {
+                stages[node.index()] = BracketStage::Lower;
+                numbers[node.index()] = self.round_number(node, &stages, &numbers);
+            }
+        }
+
+        let has_lower_bracket = stages.contains(&BracketStage::Lower);
+        let lower_rounds = rounds_in(&stages, &numbers, BracketStage::Lower);
+        let grand_final_rounds = rounds_in(&stages, &numbers, BracketStage::GrandFinal);
+        let third_place_rounds = rounds_in(&stages, &numbers, BracketStage::ThirdPlace);
+        stages
+            .into_iter()
+            .zip(numbers)
+            .map(|(stage, number)| {
+                let rounds = match stage {
+                    BracketStage::Upper => upper_rounds,
+                    BracketStage::Lower => lower_rounds,
+                    BracketStage::GrandFinal => grand_final_rounds,
+                    BracketStage::ThirdPlace => third_place_rounds,
+                };
+                Round {
+                    stage,
+                    number,
+                    label: RoundLabel::new(stage, number, rounds),
+                    has_lower_bracket,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the depth of a match within its stage,
+    /// given the stages and round numbers of its previous matches.
+    fn round_number(&self, node: NodeIndex, stages: &[BracketStage], numbers: &[u32]) -> u32 {
+        let stage = stages[node.index()];
+        self.tree
+            .neighbors_directed(node, Direction::Incoming)
+            .filter(|prev| stages[prev.index()] == stage)
+            .map(|prev| numbers[prev.index()] + 1)
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Returns `true` if no match follows the given one.
+    fn is_last_match(&self, node: NodeIndex) -> bool {
+        self.tree
+            .neighbors_directed(node, Direction::Outgoing)
+            .next()
+            .is_none()
+    }
+
+    fn link_previous_matches(&mut self, node: NodeIndex) {
+        let prev_matches = self.tree[node].previous_matches.clone();
+        for prev_match in prev_matches {
+            if let Some(&prev_node) = self.nodes.get(&prev_match.match_id) {
+                self.tree.add_edge(prev_node, node, prev_match.r#type);
+            }
+        }
+    }
+
+    fn unlink_previous_matches(&mut self, node: NodeIndex) {
+        while let Some(edge) = self.tree.first_edge(node, Direction::Incoming) {
+            self.tree.remove_edge(edge);
+        }
+    }
+
+    /// Returns the cycle going through the given match, if any.
+    fn find_cycle(&self, node: NodeIndex) -> Option<BracketIssue> {
+        let component = petgraph::algo::tarjan_scc(&self.tree)
+            .into_iter()
+            .find(|component| component.contains(&node))?;
+        if component.len() == 1 && !self.tree.contains_edge(node, node) {
+            return None;
+        }
+
+        let mut match_ids = component
+            .into_iter()
+            .map(|node| self.tree[node].inner.id)
+            .collect::<Vec<_>>();
+        match_ids.sort_unstable();
+        Some(BracketIssue::Cycle { match_ids })
+    }
 
     /// Check if the bracket is acyclic.
     #[must_use]
+    pub fn is_acyclic(&self) -> bool {
+        !petgraph::algo::is_cyclic_directed(&self.tree)
+    }
+
+    /// Check if the bracket is acyclic.
+    #[must_use]
+    #[deprecated(note = "use `is_acyclic` instead")]
     pub fn is_asyclic(&self) -> bool {
-        petgraph::algo::is_cyclic_directed(&self.tree)
+        self.is_acyclic()
     }
 
     /// Returns the number of sub-brackets in the bracket.
@@ -100,6 +498,21 @@ impl TournamentBracket {
     }
 }
 
+impl From<Vec<TournamentBracketMatch>> for TournamentBracket {
+    fn from(matches: Vec<TournamentBracketMatch>) -> Self {
+        Self::new(matches)
+    }
+}
+
+impl Serialize for TournamentBracket {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.tree.node_weights())
+    }
+}
+
 impl AsRef<Graph<TournamentBracketMatch, PreviousMatchType>> for TournamentBracket {
     fn as_ref(&self) -> &Graph<TournamentBracketMatch, PreviousMatchType> {
         &self.tree
@@ -111,9 +524,12 @@ mod tests {
     use time::OffsetDateTime;
 
     use super::*;
-    use crate::model::matches::CompactMatch;
+    use crate::model::{
+        matches::{CompactMatch, MatchResult, MatchStatus},
+        Winner,
+    };
 
-    macro_rules! make_bracket {
+    macro_rules! make_matches {
         (
             $(
                 $id:literal => $time:literal
@@ -146,9 +562,11 @@ mod tests {
                         rescheduled: None,
                         slug: "".into(),
                         status: crate::model::matches::MatchStatus::NotStarted,
+                        streams_list: vec![],
                         tournament_id: 0,
                         winner: None,
                     },
+                    games: vec![],
                     previous_matches: vec![
                         $(
                             TournamentPreviousMatch {
@@ -162,10 +580,16 @@ mod tests {
                 };
                 matches.push(m);
             )*
-            TournamentBracket::new(matches)
+            matches
         }};
     }
 
+    macro_rules! make_bracket {
+        ($($tt:tt)*) => {
+            TournamentBracket::new(make_matches!($($tt)*))
+        };
+    }
+
     #[test]
     fn test_bracket_new() {
         let _ = make_bracket! {
@@ -194,4 +618,391 @@ mod tests {
         };
         assert_eq!(bracket.sub_brackets(), 2);
     }
+
+    #[test]
+    fn test_bracket_serialize() {
+        let bracket = make_bracket! {
+            1 => 0,
+            2 => 1,
+            3 => 2 => (1, Winner) => (2, Winner),
+            4 => 3 => (1, Loser) => (2, Loser),
+        };
+        let json = serde_json::to_string(&bracket).unwrap();
+        let de: TournamentBracket = serde_json::from_str(&json).unwrap();
+
+        assert!(de.tree.node_weights().eq(bracket.tree.node_weights()));
+        assert_eq!(de.tree.edge_count(), bracket.tree.edge_count());
+        assert_eq!(serde_json::to_string(&de).unwrap(), json);
+    }
+
+    #[test]
+    fn test_bracket_serialize_out_of_order() {
+        // Rejected by `try_new`, as the final is scheduled before its previous matches
+        let bracket = TournamentBracket::new(make_matches! {
+            3 => 0 => (1, Winner) => (2, Winner),
+            1 => 1,
+            2 => 2,
+        });
+        let json = serde_json::to_string(&bracket).unwrap();
+        let de: TournamentBracket = serde_json::from_str(&json).unwrap();
+
+        assert!(de.tree.node_weights().eq(bracket.tree.node_weights()));
+        assert_eq!(de.tree.edge_count(), 2);
+        assert_eq!(de.round(3), bracket.round(3));
+        assert_eq!(serde_json::to_string(&de).unwrap(), json);
+    }
+
+    #[test]
+    fn test_bracket_try_new() {
+        let bracket = TournamentBracket::try_new(make_matches! {
+            1 => 0,
+            2 => 1,
+            3 => 2 => (1, Winner) => (2, Winner),
+        })
+        .unwrap();
+        assert!(bracket.is_acyclic());
+        assert_eq!(bracket.tree.edge_count(), 2);
+        assert_eq!(bracket.get(3).unwrap().inner.id, 3);
+        assert!(bracket.get(4).is_none());
+    }
+
+    #[test]
+    fn test_bracket_schedule_independent() {
+        // The final is scheduled first, its previous matches must still be linked
+        let matches = make_matches! {
+            3 => 0 => (1, Winner) => (2, Winner),
+            1 => 1,
+            2 => 2,
+        };
+        let bracket = TournamentBracket::new(matches.clone());
+        assert_eq!(bracket.tree.edge_count(), 2);
+        assert_eq!(bracket.sub_brackets(), 1);
+        assert_eq!(bracket.round(1).unwrap().label, RoundLabel::Semifinal);
+        assert_eq!(bracket.round(3).unwrap().label, RoundLabel::Final);
+
+        let err = TournamentBracket::try_new(matches).unwrap_err();
+        assert_eq!(
+            err.issues,
+            vec![
+                BracketIssue::ScheduledBeforePreviousMatch {
+                    match_id: 3,
+                    previous_match_id: 1,
+                },
+                BracketIssue::ScheduledBeforePreviousMatch {
+                    match_id: 3,
+                    previous_match_id: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bracket_try_new_issues() {
+        let matches = make_matches! {
+            1 => 0,
+            1 => 1,
+            2 => 2 => (1, Winner) => (5, Winner),
+            3 => 3 => (4, Winner),
+            4 => 4 => (3, Loser),
+        };
+        let bracket = TournamentBracket::new(matches.clone());
+        assert_eq!(bracket.tree.node_count(), 4);
+        assert!(!bracket.is_acyclic());
+
+        let err = TournamentBracket::try_new(matches).unwrap_err();
+        assert_eq!(
+            err.issues,
+            vec![
+                BracketIssue::DuplicateMatch { match_id: 1 },
+                BracketIssue::UnknownPreviousMatch {
+                    match_id: 2,
+                    previous_match_id: 5,
+                },
+                BracketIssue::ScheduledBeforePreviousMatch {
+                    match_id: 3,
+                    previous_match_id: 4,
+                },
+                BracketIssue::Cycle {
+                    match_ids: vec![3, 4],
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid bracket: duplicate match 1, \
+             match 2 refers to unknown previous match 5, \
+             match 3 is scheduled before its previous match 4, \
+             cycle between matches [3, 4]"
+        );
+    }
+
+    #[test]
+    fn test_bracket_add_match() {
+        let mut matches = make_matches! {
+            1 => 0,
+            2 => 1,
+            3 => 2 => (1, Winner) => (2, Winner),
+        };
+        let mut bracket = TournamentBracket::new_empty();
+
+        // Added out of order, the final is linked once its previous matches are added
+        bracket.add_match(matches.pop().unwrap()).unwrap();
+        assert_eq!(bracket.tree.edge_count(), 0);
+        bracket.add_match(matches.pop().unwrap()).unwrap();
+        bracket.add_match(matches.pop().unwrap()).unwrap();
+        assert_eq!(bracket.tree.edge_count(), 2);
+        assert_eq!(bracket.sub_brackets(), 1);
+
+        let duplicate = bracket.get(1).unwrap().clone();
+        assert_eq!(
+            bracket.add_match(duplicate).unwrap_err().issues,
+            vec![BracketIssue::DuplicateMatch { match_id: 1 }]
+        );
+
+        let cycle = make_matches! { 6 => 5 => (6, Winner) }.pop().unwrap();
+        assert_eq!(
+            bracket.add_match(cycle).unwrap_err().issues,
+            vec![BracketIssue::Cycle { match_ids: vec![6] }]
+        );
+        assert!(bracket.get(6).is_none());
+        assert_eq!(bracket.tree.node_count(), 3);
+
+        let cycle = make_matches! { 4 => 3 => (3, Winner) }.pop().unwrap();
+        bracket.add_match(cycle).unwrap();
+        let cycle = make_matches! { 5 => 4 => (4, Winner) }.pop().unwrap();
+        bracket.add_match(cycle).unwrap();
+        let mut cycle = bracket.get(3).unwrap().clone();
+        cycle.previous_matches.push(TournamentPreviousMatch {
+            match_id: 5,
+            r#type: PreviousMatchType::Loser,
+        });
+        let err = bracket.update_match(cycle).unwrap_err();
+        assert_eq!(
+            err.issues,
+            vec![BracketIssue::Cycle {
+                match_ids: vec![3, 4, 5],
+            }]
+        );
+        assert!(bracket.is_acyclic());
+        assert_eq!(bracket.tree.edge_count(), 4);
+        assert_eq!(bracket.get(3).unwrap().previous_matches.len(), 2);
+    }
+
+    #[test]
+    fn test_bracket_update_match() {
+        let mut bracket = make_bracket! {
+            1 => 0,
+            2 => 1,
+            3 => 2 => (1, Winner),
+        };
+
+        let mut m = bracket.get(3).unwrap().clone();
+        m.previous_matches.push(TournamentPreviousMatch {
+            match_id: 2,
+            r#type: PreviousMatchType::Winner,
+        });
+        m.inner.status = MatchStatus::Running;
+        let old = bracket.update_match(m).unwrap();
+        assert_eq!(old.inner.status, MatchStatus::NotStarted);
+        assert_eq!(bracket.get(3).unwrap().inner.status, MatchStatus::Running);
+        assert_eq!(bracket.tree.edge_count(), 2);
+
+        let unknown = make_matches! { 4 => 3 }.pop().unwrap();
+        assert_eq!(
+            bracket.update_match(unknown).unwrap_err().issues,
+            vec![BracketIssue::UnknownMatch { match_id: 4 }]
+        );
+    }
+
+    #[test]
+    fn test_bracket_diff() {
+        let old = make_bracket! {
+            1 => 0,
+            2 => 1,
+            3 => 2 => (1, Winner) => (2, Winner),
+        };
+        assert!(old.diff(&old).is_empty());
+
+        let mut matches = make_matches! {
+            2 => 1,
+            3 => 2 => (1, Winner) => (2, Winner),
+            4 => 3 => (3, Winner),
+        };
+        matches[0].inner.status = MatchStatus::Finished;
+        matches[0].inner.winner = Some(Winner::Team {
+            id: Some(10),
+            team: None,
+        });
+        matches[0].results = vec![
+            MatchResult::Team {
+                score: 2,
+                team_id: 10,
+            },
+            MatchResult::Team {
+                score: 1,
+                team_id: 11,
+            },
+        ];
+        matches[1].inner.status = MatchStatus::Running;
+        // Only the name changed, which is not tracked
+        matches[2].inner.name = "Final".into();
+        let new = TournamentBracket::new(matches);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, vec![4]);
+        assert_eq!(diff.removed, vec![1]);
+        assert_eq!(
+            diff.changed,
+            vec![
+                MatchDiff {
+                    match_id: 2,
+                    changes: vec![MatchChange::Status, MatchChange::Winner, MatchChange::Score],
+                },
+                MatchDiff {
+                    match_id: 3,
+                    changes: vec![MatchChange::Status],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bracket_rounds_single_elimination() {
+        let bracket = make_bracket! {
+            1 => 0,
+            2 => 0,
+            3 => 0,
+            4 => 0,
+            5 => 1 => (1, Winner) => (2, Winner),
+            6 => 1 => (3, Winner) => (4, Winner),
+            7 => 2 => (5, Winner) => (6, Winner),
+            8 => 2 => (5, Loser) => (6, Loser),
+        };
+
+        let rounds = bracket.rounds().collect::<Vec<_>>();
+        let labels = rounds.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec!["Quarterfinal", "Semifinal", "Final", "Third Place"]
+        );
+        assert_eq!(
+            rounds[0],
+            Round {
+                stage: BracketStage::Upper,
+                number: 1,
+                label: RoundLabel::Quarterfinal,
+                has_lower_bracket: false,
+            }
+        );
+
+        let ids = |stage, number| {
+            bracket
+                .matches_in_round(stage, number)
+                .map(|m| m.inner.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(BracketStage::Upper, 1), vec![1, 2, 3, 4]);
+        assert_eq!(ids(BracketStage::Upper, 2), vec![5, 6]);
+        assert_eq!(ids(BracketStage::Upper, 3), vec![7]);
+        assert_eq!(ids(BracketStage::ThirdPlace, 1), vec![8]);
+        assert_eq!(ids(BracketStage::Lower, 1), Vec::<u64>::new());
+        assert_eq!(bracket.round(7).unwrap().label, RoundLabel::Final);
+        assert_eq!(bracket.round(8).unwrap().stage, BracketStage::ThirdPlace);
+        assert!(bracket.round(9).is_none());
+    }
+
+    #[test]
+    fn test_bracket_rounds_double_elimination() {
+        let bracket = make_bracket! {
+            1 => 0,
+            2 => 0,
+            3 => 1 => (1, Winner) => (2, Winner),
+            4 => 1 => (1, Loser) => (2, Loser),
+            5 => 2 => (4, Winner) => (3, Loser),
+            6 => 3 => (3, Winner) => (5, Winner),
+            7 => 4 => (6, Winner) => (6, Loser),
+        };
+
+        let round = |id| bracket.round(id).unwrap();
+        assert_eq!(round(1).stage, BracketStage::Upper);
+        assert_eq!(round(1).label, RoundLabel::Semifinal);
+        assert_eq!(round(3).label, RoundLabel::Final);
+        assert_eq!(round(4).stage, BracketStage::Lower);
+        assert_eq!(round(4).label, RoundLabel::Round(1));
+        assert_eq!(round(5).stage, BracketStage::Lower);
+        assert_eq!(round(5).number, 2);
+        assert_eq!(round(5).label, RoundLabel::Final);
+        assert_eq!(round(6).stage, BracketStage::GrandFinal);
+        assert_eq!(round(7).stage, BracketStage::GrandFinal);
+        assert_eq!(round(7).number, 2);
+        assert_eq!(round(7).label, RoundLabel::GrandFinalReset);
+
+        let labels = bracket.rounds().map(|r| r.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec![
+                "Upper Bracket Semifinal",
+                "Upper Bracket Final",
+                "Lower Bracket Round 1",
+                "Lower Bracket Final",
+                "Grand Final",
+                "Grand Final Reset",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bracket_rounds_partial_double_elimination() {
+        // The first lower bracket round, before the following matches are known
+        let mut bracket = make_bracket! {
+            1 => 0,
+            2 => 0,
+            3 => 0,
+            4 => 0,
+            5 => 1 => (1, Winner) => (2, Winner),
+            6 => 1 => (3, Winner) => (4, Winner),
+            7 => 2 => (5, Winner) => (6, Winner),
+            8 => 1 => (1, Loser) => (2, Loser),
+        };
+        let round = |bracket: &TournamentBracket, id| bracket.round(id).unwrap();
+        assert_eq!(round(&bracket, 8).stage, BracketStage::Lower);
+        assert_eq!(round(&bracket, 1).to_string(), "Upper Bracket Quarterfinal");
+
+        let lower = make_matches! { 9 => 1 => (3, Loser) => (4, Loser) };
+        bracket
+            .add_match(lower.into_iter().next().unwrap())
+            .unwrap();
+        assert_eq!(round(&bracket, 8).stage, BracketStage::Lower);
+        assert_eq!(round(&bracket, 9).stage, BracketStage::Lower);
+
+        // A match between the losers of the semifinals, alongside a lower bracket
+        let lower = make_matches! { 10 => 2 => (5, Loser) => (6, Loser) };
+        bracket
+            .add_match(lower.into_iter().next().unwrap())
+            .unwrap();
+        assert_eq!(round(&bracket, 10).stage, BracketStage::Lower);
+        assert_eq!(
+            bracket
+                .rounds()
+                .filter(|r| r.stage == BracketStage::ThirdPlace)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_round_label() {
+        assert_eq!(
+            RoundLabel::new(BracketStage::Upper, 1, 4).to_string(),
+            "Round of 16"
+        );
+        assert_eq!(
+            RoundLabel::new(BracketStage::Upper, 1, 6).to_string(),
+            "Round of 64"
+        );
+        assert_eq!(
+            RoundLabel::new(BracketStage::Upper, 2, 4),
+            RoundLabel::Quarterfinal
+        );
+    }
 }
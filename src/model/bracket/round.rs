@@ -0,0 +1,95 @@
+use std::fmt::{Display, Formatter};
+
+/// The part of a bracket a match belongs to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum BracketStage {
+    /// Matches only reached by winning, the whole bracket in single elimination.
+    Upper,
+    /// Matches reached by losing a match, followed by more lower bracket matches.
+    Lower,
+    /// Matches between the winners of the upper and lower brackets.
+    GrandFinal,
+    /// The third place decider of a single elimination bracket,
+    /// between the losers of the semifinals.
+    ThirdPlace,
+}
+
+/// A round of a bracket, as computed by
+/// [`TournamentBracket::rounds`](super::TournamentBracket::rounds).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub struct Round {
+    pub stage: BracketStage,
+    /// Depth of the round within its stage, starting at 1.
+    pub number: u32,
+    pub label: RoundLabel,
+    /// Whether the bracket has a lower bracket,
+    /// in which case upper bracket rounds are displayed as such.
+    pub has_lower_bracket: bool,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.stage {
+            BracketStage::Upper if self.has_lower_bracket => {
+                write!(f, "Upper Bracket {}", self.label)
+            }
+            BracketStage::Lower => write!(f, "Lower Bracket {}", self.label),
+            BracketStage::Upper | BracketStage::GrandFinal | BracketStage::ThirdPlace => {
+                write!(f, "{}", self.label)
+            }
+        }
+    }
+}
+
+/// The name of a round.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum RoundLabel {
+    /// A round of the lower bracket, other than its final.
+    Round(u32),
+    /// An early round of the upper bracket, with the number of opponents left, e.g. 16.
+    RoundOf(u32),
+    Quarterfinal,
+    Semifinal,
+    Final,
+    GrandFinal,
+    /// The second grand final, played when the lower bracket finalist wins the first one.
+    GrandFinalReset,
+    ThirdPlace,
+}
+
+impl RoundLabel {
+    /// Returns the label of a round, given the number of rounds in its stage.
+    pub(super) const fn new(stage: BracketStage, number: u32, rounds: u32) -> Self {
+        match stage {
+            BracketStage::Upper => match rounds - number {
+                0 => Self::Final,
+                1 => Self::Semifinal,
+                2 => Self::Quarterfinal,
+                remaining => Self::RoundOf(2u32.saturating_pow(remaining + 1)),
+            },
+            BracketStage::Lower if number == rounds => Self::Final,
+            BracketStage::Lower => Self::Round(number),
+            BracketStage::GrandFinal if number > 1 => Self::GrandFinalReset,
+            BracketStage::GrandFinal => Self::GrandFinal,
+            BracketStage::ThirdPlace => Self::ThirdPlace,
+        }
+    }
+}
+
+impl Display for RoundLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Round(number) => write!(f, "Round {number}"),
+            Self::RoundOf(opponents) => write!(f, "Round of {opponents}"),
+            Self::Quarterfinal => write!(f, "Quarterfinal"),
+            Self::Semifinal => write!(f, "Semifinal"),
+            Self::Final => write!(f, "Final"),
+            Self::GrandFinal => write!(f, "Grand Final"),
+            Self::GrandFinalReset => write!(f, "Grand Final Reset"),
+            Self::ThirdPlace => write!(f, "Third Place"),
+        }
+    }
+}
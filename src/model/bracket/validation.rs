@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+
+/// A problem found while building a [`TournamentBracket`](super::TournamentBracket).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum BracketIssue {
+    /// Several matches have the same ID.
+    ///
+    /// When building a bracket, matches are sorted by `scheduled_at` first,
+    /// so only the earliest scheduled one is kept, or the first given one on ties.
+    DuplicateMatch { match_id: u64 },
+    /// A match refers to a previous match that is not in the bracket.
+    ///
+    /// Only reported by [`TournamentBracket::try_new`](super::TournamentBracket::try_new).
+    UnknownPreviousMatch {
+        match_id: u64,
+        previous_match_id: u64,
+    },
+    /// A match is scheduled before one of its previous matches.
+    ///
+    /// Only reported by [`TournamentBracket::try_new`](super::TournamentBracket::try_new).
+    ScheduledBeforePreviousMatch {
+        match_id: u64,
+        previous_match_id: u64,
+    },
+    /// The match to update is not in the bracket.
+    UnknownMatch { match_id: u64 },
+    /// Matches that are, directly or not, their own previous match.
+    /// IDs are sorted.
+    Cycle { match_ids: Vec<u64> },
+}
+
+impl Display for BracketIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateMatch { match_id } => write!(f, "duplicate match {match_id}"),
+            Self::UnknownMatch { match_id } => write!(f, "unknown match {match_id}"),
+            Self::UnknownPreviousMatch {
+                match_id,
+                previous_match_id,
+            } => write!(
+                f,
+                "match {match_id} refers to unknown previous match {previous_match_id}"
+            ),
+            Self::ScheduledBeforePreviousMatch {
+                match_id,
+                previous_match_id,
+            } => write!(
+                f,
+                "match {match_id} is scheduled before its previous match {previous_match_id}"
+            ),
+            Self::Cycle { match_ids } => write!(f, "cycle between matches {match_ids:?}"),
+        }
+    }
+}
+
+/// The report returned by [`TournamentBracket::try_new`](super::TournamentBracket::try_new)
+/// when the matches don't form a valid bracket,
+/// or when a match can't be added or updated.
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub struct BracketValidationError {
+    /// Every problem found, in the order they were found.
+    pub issues: Vec<BracketIssue>,
+}
+
+impl Display for BracketValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid bracket: ")?;
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<BracketIssue> for BracketValidationError {
+    fn from(issue: BracketIssue) -> Self {
+        Self {
+            issues: vec![issue],
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Models related to the game Counter-Strike.
+
+pub mod map;
+pub mod round;
+pub mod weapon;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// A round of a Counter-Strike game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Round {
+    /// ID of the team playing counter-terrorists.
+    pub ct: u64,
+    pub outcome: RoundOutcome,
+    /// Number of the round, starting at 1.
+    pub round: u32,
+    /// ID of the team playing terrorists.
+    pub terrorists: u64,
+    /// ID of the team that won the round.
+    pub winner_team: u64,
+}
+
+impl Round {
+    /// Returns the side that won the round.
+    #[must_use]
+    pub const fn winner_side(&self) -> Option<Side> {
+        if self.winner_team == self.ct {
+            Some(Side::CounterTerrorists)
+        } else if self.winner_team == self.terrorists {
+            Some(Side::Terrorists)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RoundOutcome {
+    /// The bomb exploded.
+    Exploded,
+    /// The bomb was defused.
+    Defused,
+    /// All players of a team were eliminated.
+    Eliminated,
+    /// The round timer ran out.
+    Timeout,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Side {
+    CounterTerrorists,
+    Terrorists,
+}
@@ -0,0 +1,12 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Weapon {
+    pub id: u64,
+    pub image_url: Option<String>,
+    /// The category of the weapon, e.g. `Rifle` or `Pistol`.
+    pub kind: CompactString,
+    pub name: CompactString,
+}
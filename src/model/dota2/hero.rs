@@ -0,0 +1,54 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Hero {
+    #[serde(default, rename = "agi_gain")]
+    pub agility_per_level: Option<f64>,
+    #[serde(default)]
+    pub armor: Option<f64>,
+    #[serde(default)]
+    pub attack_range: Option<f64>,
+    #[serde(default, rename = "base_agi")]
+    pub base_agility: Option<f64>,
+    #[serde(default, rename = "base_int")]
+    pub base_intelligence: Option<f64>,
+    #[serde(default, rename = "base_str")]
+    pub base_strength: Option<f64>,
+    #[serde(default)]
+    pub hp: Option<f64>,
+    #[serde(default, rename = "hpregen")]
+    pub hp_regen: Option<f64>,
+    pub id: u64,
+    pub image_url: Option<String>,
+    #[serde(default, rename = "int_gain")]
+    pub intelligence_per_level: Option<f64>,
+    /// Display name of the hero, e.g. `Anti-Mage`.
+    pub localized_name: CompactString,
+    #[serde(default, rename = "movespeed")]
+    pub move_speed: Option<f64>,
+    #[serde(default, rename = "mp")]
+    pub mana: Option<f64>,
+    #[serde(default, rename = "mpregen")]
+    pub mana_regen: Option<f64>,
+    /// Internal name of the hero, e.g. `npc_dota_hero_antimage`.
+    pub name: CompactString,
+    #[serde(default, rename = "primary_attr")]
+    pub primary_attribute: Option<Attribute>,
+    #[serde(default, rename = "str_gain")]
+    pub strength_per_level: Option<f64>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum Attribute {
+    #[serde(rename = "agi")]
+    Agility,
+    #[serde(rename = "int")]
+    Intelligence,
+    #[serde(rename = "str")]
+    Strength,
+    #[serde(rename = "all")]
+    Universal,
+}
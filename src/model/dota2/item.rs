@@ -0,0 +1,11 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Item {
+    pub id: u64,
+    pub image_url: Option<String>,
+    /// Internal name of the item, e.g. `item_blink`.
+    pub name: CompactString,
+}
@@ -0,0 +1,5 @@
+//! Models related to the game Dota 2.
+
+pub mod ability;
+pub mod hero;
+pub mod item;
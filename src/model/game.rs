@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::Winner;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactGame {
     /// The game begin time, UTC.
@@ -36,10 +36,11 @@ pub struct CompactGame {
     pub position: u64,
     /// The game status
     pub status: GameStatus,
+    #[serde(flatten)]
     pub winner: Option<Winner>,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum GameStatus {
@@ -0,0 +1,136 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::model::{
+    league::League, matches::Match, player::Player, series::Series, team::Team,
+    tournament::Tournament,
+};
+
+/// A change to an entity of the API.
+///
+/// Incidents can be used to incrementally synchronize data
+/// instead of re-fetching every entity.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "RawIncident")]
+#[non_exhaustive]
+pub struct Incident {
+    pub change_type: ChangeType,
+    /// ID of the changed entity.
+    pub id: u64,
+    #[serde(serialize_with = "time::serde::iso8601::serialize")]
+    pub modified_at: OffsetDateTime,
+    /// The changed entity.
+    pub object: IncidentObject,
+    /// The type of the changed entity.
+    pub r#type: IncidentType,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChangeType {
+    Creation,
+    Update,
+    Deletion,
+}
+
+/// The type of entity an incident refers to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum IncidentType {
+    League,
+    Match,
+    Player,
+    #[serde(rename = "serie")]
+    Series,
+    Team,
+    Tournament,
+}
+
+impl IncidentType {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::League => "league",
+            Self::Match => "match",
+            Self::Player => "player",
+            Self::Series => "serie",
+            Self::Team => "team",
+            Self::Tournament => "tournament",
+        }
+    }
+}
+
+/// The entity an incident refers to.
+///
+/// Deleted entities are no longer available,
+/// so only the details of the deletion are returned.
+/// Boxed to reduce size.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum IncidentObject {
+    League(Box<League>),
+    Match(Box<Match>),
+    Player(Box<Player>),
+    Series(Box<Series>),
+    Team(Box<Team>),
+    Tournament(Box<Tournament>),
+    Deleted(Deletion),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Deletion {
+    #[serde(with = "time::serde::iso8601")]
+    pub deleted_at: OffsetDateTime,
+    /// Reason of the deletion, e.g. "Duplicate".
+    pub reason: Option<CompactString>,
+    #[serde(default)]
+    pub videogame_id: Option<u64>,
+}
+
+/// The incident as returned by the API,
+/// before the object is deserialized according to its type.
+#[derive(Deserialize)]
+struct RawIncident {
+    change_type: ChangeType,
+    id: u64,
+    #[serde(with = "time::serde::iso8601")]
+    modified_at: OffsetDateTime,
+    object: Value,
+    #[serde(rename = "type")]
+    r#type: IncidentType,
+}
+
+impl TryFrom<RawIncident> for Incident {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawIncident) -> Result<Self, Self::Error> {
+        let object = if raw.change_type == ChangeType::Deletion {
+            IncidentObject::Deleted(serde_json::from_value(raw.object)?)
+        } else {
+            match raw.r#type {
+                IncidentType::League => IncidentObject::League(serde_json::from_value(raw.object)?),
+                IncidentType::Match => IncidentObject::Match(serde_json::from_value(raw.object)?),
+                IncidentType::Player => IncidentObject::Player(serde_json::from_value(raw.object)?),
+                IncidentType::Series => IncidentObject::Series(serde_json::from_value(raw.object)?),
+                IncidentType::Team => IncidentObject::Team(serde_json::from_value(raw.object)?),
+                IncidentType::Tournament => {
+                    IncidentObject::Tournament(serde_json::from_value(raw.object)?)
+                }
+            }
+        };
+
+        Ok(Self {
+            change_type: raw.change_type,
+            id: raw.id,
+            modified_at: raw.modified_at,
+            object,
+            r#type: raw.r#type,
+        })
+    }
+}
@@ -1,12 +1,12 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::{series::CompactSeries, VideoGame};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct League {
     #[serde(flatten)]
@@ -17,7 +17,7 @@ pub struct League {
     pub video_game: VideoGame,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactLeague {
     pub id: u64,
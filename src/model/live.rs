@@ -0,0 +1,71 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::model::matches::Match;
+
+/// A match with live data available.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Live {
+    /// Websocket endpoints serving the live data of the match.
+    pub endpoints: Vec<LiveEndpoint>,
+    pub event: LiveEvent,
+    #[serde(rename = "match")]
+    pub r#match: Match,
+}
+
+impl Live {
+    /// Time at which the live endpoints open, UTC.
+    #[must_use]
+    pub const fn opens_at(&self) -> Option<OffsetDateTime> {
+        self.r#match.inner.live.opens_at
+    }
+
+    /// Whether live data is supported for the match.
+    #[must_use]
+    pub const fn is_supported(&self) -> bool {
+        self.r#match.inner.live.supported
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct LiveEndpoint {
+    /// Time at which the endpoint is expected to open, UTC.
+    #[serde(with = "time::serde::iso8601::option")]
+    pub expected_at: Option<OffsetDateTime>,
+    pub match_id: u64,
+    /// Whether the endpoint is open.
+    pub open: bool,
+    #[serde(rename = "type")]
+    pub r#type: LiveEndpointType,
+    /// Websocket URL of the endpoint.
+    pub url: String,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LiveEndpointType {
+    /// Snapshots of the game state.
+    Frames,
+    /// Events happening during the game.
+    Events,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct LiveEvent {
+    #[serde(with = "time::serde::iso8601::option")]
+    pub begin_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::iso8601::option")]
+    pub end_at: Option<OffsetDateTime>,
+    /// Slug of the video game.
+    pub game: CompactString,
+    pub id: u64,
+    /// Whether the event is currently live.
+    pub is_active: bool,
+    pub stream_url: Option<String>,
+    pub tournament_id: u64,
+}
@@ -1,6 +1,6 @@
 use compact_str::CompactString;
-use serde::Deserialize;
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+use serde::{Deserialize, Serialize};
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Champion {
     pub armor: f64,
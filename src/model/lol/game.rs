@@ -0,0 +1,160 @@
+use std::ops::{Deref, DerefMut};
+
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{game::CompactGame, player::CompactPlayer, team::CompactTeam};
+
+/// A League of Legends game, with statistics for each team and player.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Game {
+    #[serde(flatten)]
+    pub inner: CompactGame,
+
+    pub players: Vec<GamePlayer>,
+    pub teams: Vec<GameTeam>,
+}
+
+impl Game {
+    /// Returns the team playing on the given side.
+    #[must_use]
+    pub fn team(&self, color: Side) -> Option<&GameTeam> {
+        self.teams.iter().find(|team| team.color == color)
+    }
+
+    /// Returns the players of the given team.
+    pub fn players_of(&self, team_id: u64) -> impl Iterator<Item = &GamePlayer> {
+        self.players
+            .iter()
+            .filter(move |player| player.team.id == team_id)
+    }
+}
+
+impl Deref for Game {
+    type Target = CompactGame;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Game {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Statistics of a team in a game.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GameTeam {
+    /// IDs of the champions banned by the team.
+    pub bans: Vec<u64>,
+    pub color: Side,
+    /// Whether the team got the first kill of the game.
+    pub first_blood: Option<bool>,
+    pub gold_earned: Option<u64>,
+    pub kills: Option<u32>,
+    #[serde(flatten)]
+    pub objectives: Objectives,
+    pub player_ids: Vec<u64>,
+    pub team: CompactTeam,
+}
+
+/// Objectives taken by a team in a game.
+///
+/// Only available for games with detailed statistics.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Objectives {
+    pub baron_kills: Option<u32>,
+    pub chemtech_drake_kills: Option<u32>,
+    pub cloud_drake_kills: Option<u32>,
+    /// Total number of dragons killed, including elder dragons.
+    pub dragon_kills: Option<u32>,
+    pub elder_drake_kills: Option<u32>,
+    pub first_baron: Option<bool>,
+    pub first_dragon: Option<bool>,
+    pub first_herald: Option<bool>,
+    pub first_inhibitor: Option<bool>,
+    pub first_tower: Option<bool>,
+    pub herald_kills: Option<u32>,
+    pub hextech_drake_kills: Option<u32>,
+    pub infernal_drake_kills: Option<u32>,
+    pub inhibitor_kills: Option<u32>,
+    pub mountain_drake_kills: Option<u32>,
+    pub ocean_drake_kills: Option<u32>,
+    pub tower_kills: Option<u32>,
+}
+
+/// Statistics of a player in a game.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GamePlayer {
+    pub assists: u32,
+    /// The champion picked by the player.
+    pub champion: GameChampion,
+    /// Number of minions and monsters killed.
+    #[serde(rename = "cs")]
+    pub creep_score: Option<u32>,
+    pub deaths: u32,
+    pub gold_earned: Option<u64>,
+    pub gold_spent: Option<u64>,
+    /// Items held by the player at the end of the game.
+    pub items: Vec<GameItem>,
+    pub kills: u32,
+    /// Champion level at the end of the game.
+    pub level: Option<u32>,
+    pub player: CompactPlayer,
+    pub player_id: u64,
+    pub role: Option<Role>,
+    pub team: CompactTeam,
+}
+
+impl GamePlayer {
+    /// Returns the kills/deaths/assists ratio, `(kills + assists) / max(deaths, 1)`.
+    #[must_use]
+    pub fn kda(&self) -> f64 {
+        f64::from(self.kills + self.assists) / f64::from(self.deaths.max(1))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GameChampion {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GameItem {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+}
+
+/// The side of the map a team plays on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Blue,
+    Red,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum Role {
+    #[serde(rename = "top")]
+    Top,
+    #[serde(rename = "jun")]
+    Jungle,
+    #[serde(rename = "mid")]
+    Mid,
+    #[serde(rename = "adc")]
+    Bottom,
+    #[serde(rename = "sup")]
+    Support,
+}
@@ -1,7 +1,7 @@
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Item {
     pub flat_armor_mod: Option<u64>,
@@ -1,5 +1,7 @@
 //! Models related to the game League of Legends.
 
 pub mod champion;
+pub mod game;
 pub mod item;
 pub mod spell;
+pub mod stats;
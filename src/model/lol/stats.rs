@@ -0,0 +1,191 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{lol::game::GameChampion, player::CompactPlayer, team::CompactTeam, VideoGame};
+
+/// A player with their statistics.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PlayerStats {
+    #[serde(flatten)]
+    pub inner: CompactPlayer,
+
+    pub current_team: Option<CompactTeam>,
+    #[serde(rename = "current_videogame")]
+    pub current_video_game: Option<VideoGame>,
+    pub stats: PlayerStatistics,
+}
+
+impl Deref for PlayerStats {
+    type Target = CompactPlayer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for PlayerStats {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PlayerStatistics {
+    /// Averages per game.
+    pub averages: PlayerAverages,
+    /// Champions most played by the player.
+    #[serde(default)]
+    pub favorite_champions: Vec<ChampionStats>,
+    pub games_count: u32,
+    pub totals: PlayerTotals,
+}
+
+impl PlayerStatistics {
+    /// Returns the ratio of games won, `None` if no game was played.
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        win_rate(self.totals.games_won, self.totals.games_played)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PlayerAverages {
+    pub assists: Option<f64>,
+    #[serde(rename = "cs_at_14")]
+    pub creep_score_at_14: Option<f64>,
+    /// Creep score difference with the opposing laner at 14 minutes.
+    #[serde(rename = "cs_diff_at_14")]
+    pub creep_score_diff_at_14: Option<f64>,
+    pub deaths: Option<f64>,
+    pub gold_earned: Option<f64>,
+    pub gold_spent: Option<f64>,
+    pub kills: Option<f64>,
+    pub minions_killed: Option<f64>,
+    pub wards_placed: Option<f64>,
+}
+
+impl PlayerAverages {
+    /// Returns the average kills/deaths/assists ratio.
+    #[must_use]
+    pub fn kda(&self) -> Option<f64> {
+        Some((self.kills? + self.assists?) / self.deaths?.max(1.0))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PlayerTotals {
+    pub assists: u32,
+    pub deaths: u32,
+    pub games_lost: u32,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub kills: u32,
+    pub wards_placed: Option<u32>,
+}
+
+/// A team with its statistics.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TeamStats {
+    #[serde(flatten)]
+    pub inner: CompactTeam,
+
+    #[serde(rename = "current_videogame")]
+    pub current_video_game: Option<VideoGame>,
+    #[serde(default)]
+    pub players: Vec<CompactPlayer>,
+    pub stats: TeamStatistics,
+}
+
+impl Deref for TeamStats {
+    type Target = CompactTeam;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for TeamStats {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TeamStatistics {
+    /// Averages per game.
+    pub averages: TeamAverages,
+    pub games_count: u32,
+    /// Champions most banned by the team.
+    #[serde(default)]
+    pub most_banned_champions: Vec<ChampionStats>,
+    /// Champions most picked by the team.
+    #[serde(default)]
+    pub most_picked_champions: Vec<ChampionStats>,
+    pub totals: TeamTotals,
+}
+
+impl TeamStatistics {
+    /// Returns the ratio of games won, `None` if no game was played.
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        win_rate(self.totals.games_won, self.totals.games_played)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TeamAverages {
+    pub baron_kills: Option<f64>,
+    pub deaths: Option<f64>,
+    pub dragon_kills: Option<f64>,
+    /// Average game length, in seconds.
+    pub game_length: Option<f64>,
+    pub gold_earned: Option<f64>,
+    pub herald_kills: Option<f64>,
+    pub inhibitor_kills: Option<f64>,
+    pub kills: Option<f64>,
+    pub tower_kills: Option<f64>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TeamTotals {
+    pub baron_kills: Option<u32>,
+    pub dragon_kills: Option<u32>,
+    pub first_blood: Option<u32>,
+    pub first_tower: Option<u32>,
+    pub games_lost: u32,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub kills: Option<u32>,
+    pub tower_kills: Option<u32>,
+}
+
+/// Statistics of a champion in a player's or team's champion pool.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ChampionStats {
+    pub champion: GameChampion,
+    pub games_count: u32,
+    /// Number of games won with the champion, `None` for bans.
+    pub wins: Option<u32>,
+}
+
+impl ChampionStats {
+    /// Returns the ratio of games won with the champion.
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        win_rate(self.wins?, self.games_count)
+    }
+}
+
+fn win_rate(won: u32, played: u32) -> Option<f64> {
+    (played > 0).then(|| f64::from(won) / f64::from(played))
+}
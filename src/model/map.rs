@@ -0,0 +1,13 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+/// A map, for games whose maps have no game-specific details.
+///
+/// Used by the Counter-Strike, PUBG and Rainbow Six Siege endpoints.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Map {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+}
@@ -1,24 +1,30 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::{
-    league::CompactLeague, player::CompactPlayer, series::CompactSeries, team::CompactTeam,
-    tournament::CompactTournament, VideoGame, Winner,
+    game::CompactGame,
+    league::CompactLeague,
+    player::CompactPlayer,
+    series::CompactSeries,
+    stream::{Stream, StreamPreference},
+    team::CompactTeam,
+    tournament::CompactTournament,
+    VideoGame, VideoGameVersion, Winner,
 };
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Match {
     #[serde(flatten)]
     pub inner: CompactMatch,
 
-    // pub games: Vec<_>
+    pub games: Vec<CompactGame>,
     pub league: CompactLeague,
     pub league_id: u64,
-    // pub opponents: Vec<_>
+    pub opponents: Vec<CompactMatchOpponent>,
     pub results: Vec<MatchResult>,
     pub serie: CompactSeries,
     pub serie_id: u64,
@@ -27,10 +33,9 @@ pub struct Match {
     pub video_game: VideoGame,
     #[serde(rename = "videogame_version")]
     pub video_game_version: Option<MatchVideoGameVersion>,
-    // pub winner: todo
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum MatchResult {
@@ -38,15 +43,9 @@ pub enum MatchResult {
     Player { score: u32, player_id: u64 },
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-#[non_exhaustive]
-pub struct MatchVideoGameVersion {
-    /// Whether this videogame version is current
-    pub current: bool,
-    pub name: CompactString,
-}
+pub type MatchVideoGameVersion = VideoGameVersion;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactMatch {
     #[serde(with = "time::serde::iso8601::option")]
@@ -77,13 +76,24 @@ pub struct CompactMatch {
     pub scheduled_at: Option<OffsetDateTime>,
     pub slug: String,
     pub status: MatchStatus,
-    // pub streams_list: Vec<_>,
+    pub streams_list: Vec<Stream>,
     pub tournament_id: u64,
     #[serde(flatten)]
     pub winner: Option<Winner>,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+impl CompactMatch {
+    /// Returns the stream of the match best matching the preference,
+    /// `None` if the match has no streams.
+    ///
+    /// See [`StreamPreference`] for how streams are ranked.
+    #[must_use]
+    pub fn preferred_stream(&self, preference: &StreamPreference) -> Option<&Stream> {
+        preference.select(&self.streams_list)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum MatchType {
@@ -95,7 +105,7 @@ pub enum MatchType {
     RedBullHomeGround,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum MatchStatus {
@@ -106,7 +116,34 @@ pub enum MatchStatus {
     Running,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+impl MatchType {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::AllGamesPlayed => "all_games_played",
+            Self::BestOf => "best_of",
+            Self::Custom => "custom",
+            Self::FirstTo => "first_to",
+            Self::OwBestOf => "ow_best_of",
+            Self::RedBullHomeGround => "red_bull_home_ground",
+        }
+    }
+}
+
+impl MatchStatus {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Canceled => "canceled",
+            Self::Finished => "finished",
+            Self::NotStarted => "not_started",
+            Self::Postponed => "postponed",
+            Self::Running => "running",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MatchLive {
     #[serde(with = "time::serde::iso8601::option")]
@@ -116,7 +153,7 @@ pub struct MatchLive {
     pub url: Option<String>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "type", content = "opponent")]
 #[non_exhaustive]
 pub enum CompactMatchOpponent {
@@ -124,7 +161,7 @@ pub enum CompactMatchOpponent {
     Player(CompactPlayer),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "opponent_type", content = "opponents")]
 #[non_exhaustive]
 pub enum MatchOpponents {
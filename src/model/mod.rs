@@ -1,38 +1,93 @@
 //! Contains the data models for the different endpoints of the API.
 
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display, num::ParseIntError, str::FromStr};
 
-use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 pub mod bracket;
+pub mod csgo;
+pub mod dota2;
 pub mod game;
+pub mod incident;
 pub mod league;
+pub mod live;
 pub mod lol;
+pub mod map;
 pub mod matches;
+pub mod ow;
 pub mod player;
+pub mod pubg;
+pub mod r6siege;
+pub mod rl;
 pub mod series;
+pub mod stream;
 pub mod team;
 pub mod tournament;
+pub mod valorant;
+mod videogame;
 mod winner;
 
+pub use videogame::{VideoGame, VideoGameSlug, VideoGameVersion};
 pub use winner::Winner;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
-#[non_exhaustive]
-pub struct VideoGame {
-    pub id: u64,
-    pub name: CompactString,
-    pub slug: CompactString,
-    pub current_version: Option<CompactString>,
-}
-
-/// A unique identifier for an entity.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize)]
+/// A unique identifier for an entity, either its numeric ID or its slug.
+///
+/// The slug is borrowed when possible.
+/// Use [`Identifier::into_owned`] to get an identifier that can be stored
+/// or sent across tasks, along with the endpoints built from it.
+///
+/// # Example
+/// ```
+/// use pandascore::model::Identifier;
+///
+/// assert_eq!("14032".parse(), Ok(Identifier::Id(14032)));
+/// assert_eq!("faker".parse(), Ok(Identifier::from("faker")));
+/// assert!("not a slug".parse::<Identifier>().is_err());
+/// assert_eq!(Identifier::slug("Not A Slug").to_string(), "Not A Slug");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum Identifier<'a> {
     Id(u64),
-    Slug(&'a str),
+    Slug(#[serde(borrow)] Cow<'a, str>),
+}
+
+impl<'a> Identifier<'a> {
+    /// Parses a string without copying it,
+    /// as an ID if it only contains digits and as a slug otherwise.
+    ///
+    /// # Errors
+    /// Returns an error if the ID overflows,
+    /// or the slug is empty or contains characters other than
+    /// lowercase ASCII letters, digits, `-`, `_` and `:`.
+    /// Use [`Identifier::slug`] to skip this check.
+    pub fn parse(s: &'a str) -> Result<Self, ParseIdentifierError> {
+        if s.is_empty() {
+            return Err(ParseIdentifierError::Empty);
+        }
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(Self::Id(s.parse()?));
+        }
+        s.chars()
+            .find(|&c| !matches!(c, 'a'..='z' | '0'..='9' | '-' | '_' | ':'))
+            .map_or(Ok(Self::Slug(Cow::Borrowed(s))), |c| {
+                Err(ParseIdentifierError::InvalidCharacter(c))
+            })
+    }
+
+    /// Creates a slug identifier without validating it.
+    pub fn slug(slug: impl Into<Cow<'a, str>>) -> Self {
+        Self::Slug(slug.into())
+    }
+
+    /// Converts the identifier into one that owns its slug.
+    #[must_use]
+    pub fn into_owned(self) -> Identifier<'static> {
+        match self {
+            Self::Id(id) => Identifier::Id(id),
+            Self::Slug(slug) => Identifier::Slug(Cow::Owned(slug.into_owned())),
+        }
+    }
 }
 
 impl From<u64> for Identifier<'_> {
@@ -42,10 +97,28 @@ impl From<u64> for Identifier<'_> {
 }
 impl<'a> From<&'a str> for Identifier<'a> {
     fn from(slug: &'a str) -> Self {
+        Self::Slug(Cow::Borrowed(slug))
+    }
+}
+impl From<String> for Identifier<'_> {
+    fn from(slug: String) -> Self {
+        Self::Slug(Cow::Owned(slug))
+    }
+}
+impl<'a> From<Cow<'a, str>> for Identifier<'a> {
+    fn from(slug: Cow<'a, str>) -> Self {
         Self::Slug(slug)
     }
 }
 
+impl FromStr for Identifier<'static> {
+    type Err = ParseIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identifier::parse(s).map(Identifier::into_owned)
+    }
+}
+
 impl Display for Identifier<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -55,6 +128,18 @@ impl Display for Identifier<'_> {
     }
 }
 
+/// An error that occurred while parsing an [`Identifier`].
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseIdentifierError {
+    #[error("Empty identifier")]
+    Empty,
+    #[error("Invalid ID: {0}")]
+    InvalidId(#[from] ParseIntError),
+    #[error("Invalid character in slug: {0:?}")]
+    InvalidCharacter(char),
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum EventStatus {
@@ -73,3 +158,60 @@ impl EventStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifier_from_str() {
+        assert_eq!("14032".parse(), Ok(Identifier::Id(14032)));
+        assert_eq!(
+            "league-of-legends".parse(),
+            Ok(Identifier::Slug("league-of-legends".into()))
+        );
+        assert_eq!("t1".parse(), Ok(Identifier::Slug("t1".into())));
+        assert_eq!(
+            "lck:2024_summer".parse(),
+            Ok(Identifier::Slug("lck:2024_summer".into()))
+        );
+
+        assert_eq!("".parse::<Identifier>(), Err(ParseIdentifierError::Empty));
+        assert_eq!(
+            "Faker".parse::<Identifier>(),
+            Err(ParseIdentifierError::InvalidCharacter('F'))
+        );
+        assert_eq!(
+            "g2 esports".parse::<Identifier>(),
+            Err(ParseIdentifierError::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            "../teams".parse::<Identifier>(),
+            Err(ParseIdentifierError::InvalidCharacter('.'))
+        );
+        assert!(matches!(
+            "99999999999999999999".parse::<Identifier>(),
+            Err(ParseIdentifierError::InvalidId(_))
+        ));
+    }
+
+    #[test]
+    fn test_identifier_into_owned() {
+        let slug = String::from("faker");
+        let id = Identifier::parse(&slug).unwrap();
+        assert!(matches!(id, Identifier::Slug(Cow::Borrowed(_))));
+
+        let owned = id.into_owned();
+        drop(slug);
+        assert!(matches!(&owned, Identifier::Slug(Cow::Owned(s)) if s == "faker"));
+        assert_eq!(owned.to_string(), "faker");
+    }
+
+    #[test]
+    fn test_identifier_slug() {
+        let slug = String::from("G2 Esports");
+        let id = Identifier::slug(slug.as_str());
+        assert!(matches!(id, Identifier::Slug(Cow::Borrowed("G2 Esports"))));
+        assert_eq!(Identifier::slug(slug.clone()), id);
+    }
+}
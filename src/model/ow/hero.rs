@@ -0,0 +1,27 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Hero {
+    pub difficulty: Option<u8>,
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+    pub portrait_url: Option<String>,
+    pub real_name: Option<CompactString>,
+    pub role: HeroRole,
+    pub slug: CompactString,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum HeroRole {
+    Damage,
+    Support,
+    Tank,
+    /// A role that no longer exists, e.g. `offense` or `defense` from Overwatch 1.
+    #[serde(other)]
+    Other,
+}
@@ -0,0 +1,13 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Map {
+    /// The game mode played on the map, e.g. `control` or `escort`.
+    pub game_mode: CompactString,
+    pub id: u64,
+    pub name: CompactString,
+    pub slug: CompactString,
+    pub thumbnail_url: Option<String>,
+}
@@ -0,0 +1,4 @@
+//! Models related to the game Overwatch.
+
+pub mod hero;
+pub mod map;
@@ -1,12 +1,12 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::{Date, OffsetDateTime};
 
 use crate::model::{team::CompactTeam, VideoGame};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Player {
     #[serde(flatten)]
@@ -17,7 +17,7 @@ pub struct Player {
     pub current_video_game: Option<VideoGame>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactPlayer {
     /// Whether player is active.
@@ -57,7 +57,7 @@ pub struct CompactPlayer {
 /// time::serde::format_description!(birthday_format, Date, "[year]-[month]-[day]");
 /// ```
 mod birthday_format {
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
     use time::{format_description::BorrowedFormatItem, Date};
 
     const FORMAT: &[BorrowedFormatItem<'static>] =
@@ -71,6 +71,16 @@ mod birthday_format {
         s.map(|s| Date::parse(s, FORMAT).map_err(serde::de::Error::custom))
             .transpose()
     }
+
+    #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S>(date: &Option<Date>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        date.map(|date| date.format(FORMAT).map_err(serde::ser::Error::custom))
+            .transpose()?
+            .serialize(serializer)
+    }
 }
 
 impl Deref for Player {
@@ -0,0 +1,3 @@
+//! Models related to the game PUBG.
+
+pub mod map;
@@ -0,0 +1,4 @@
+//! Models related to the game Rainbow Six Siege.
+
+pub mod map;
+pub mod operator;
@@ -0,0 +1,19 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Operator {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+    pub side: Option<OperatorSide>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OperatorSide {
+    Attacker,
+    Defender,
+}
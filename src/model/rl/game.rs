@@ -0,0 +1,120 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{game::CompactGame, player::CompactPlayer, team::CompactTeam};
+
+/// A Rocket League game, with statistics for each team and player.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Game {
+    #[serde(flatten)]
+    pub inner: CompactGame,
+
+    pub players: Vec<GamePlayer>,
+    pub teams: Vec<GameTeam>,
+}
+
+impl Game {
+    /// Returns the team playing on the given side.
+    #[must_use]
+    pub fn team(&self, color: Side) -> Option<&GameTeam> {
+        self.teams.iter().find(|team| team.color == color)
+    }
+
+    /// Returns the players of the given team.
+    pub fn players_of(&self, team_id: u64) -> impl Iterator<Item = &GamePlayer> {
+        self.players
+            .iter()
+            .filter(move |player| player.team.id == team_id)
+    }
+}
+
+impl Deref for Game {
+    type Target = CompactGame;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Game {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Statistics of a team in a game.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GameTeam {
+    pub color: Side,
+    pub stats: Option<Stats>,
+    pub team: CompactTeam,
+}
+
+/// Statistics of a player in a game.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GamePlayer {
+    pub player: CompactPlayer,
+    pub stats: Option<Stats>,
+    pub team: CompactTeam,
+}
+
+/// Statistics of a player or team.
+///
+/// Only available for games with detailed statistics.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Stats {
+    pub boost: Option<BoostStats>,
+    pub core: CoreStats,
+    pub demo: Option<DemoStats>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CoreStats {
+    pub assists: u32,
+    pub goals: u32,
+    pub saves: u32,
+    pub score: u32,
+    /// Percentage of shots resulting in a goal, from 0 to 100.
+    pub shooting_percentage: Option<f64>,
+    pub shots: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct BoostStats {
+    pub amount_collected: Option<u32>,
+    pub amount_stolen: Option<u32>,
+    /// Average amount of boost held.
+    #[serde(rename = "avg_amount")]
+    pub average_amount: Option<f64>,
+    /// Boost consumed per minute.
+    #[serde(rename = "bpm")]
+    pub per_minute: Option<f64>,
+    pub count_collected_big: Option<u32>,
+    pub count_collected_small: Option<u32>,
+    /// Time spent without boost, in seconds.
+    pub time_zero_boost: Option<f64>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct DemoStats {
+    /// Number of opponents demolished.
+    pub inflicted: u32,
+    /// Number of times demolished.
+    pub taken: u32,
+}
+
+/// The side of the field a team plays on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Blue,
+    Orange,
+}
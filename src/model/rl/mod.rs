@@ -0,0 +1,3 @@
+//! Models related to the game Rocket League.
+
+pub mod game;
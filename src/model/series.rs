@@ -1,12 +1,12 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::{league::CompactLeague, tournament::CompactTournament, VideoGame, Winner};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Series {
     #[serde(flatten)]
@@ -18,7 +18,7 @@ pub struct Series {
     pub video_game: VideoGame,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactSeries {
     #[serde(with = "time::serde::iso8601::option")]
@@ -0,0 +1,193 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A stream broadcasting a match.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Stream {
+    /// URL of the embeddable player, `None` if the platform doesn't support embedding.
+    pub embed_url: Option<String>,
+    /// Language of the stream, as an ISO 639-1 code, e.g. `en`.
+    pub language: CompactString,
+    /// Whether this is the main stream of the match.
+    pub main: bool,
+    /// Whether the stream is broadcast by the organizer.
+    pub official: bool,
+    /// URL of the stream page.
+    pub raw_url: String,
+}
+
+impl Stream {
+    /// Returns the platform hosting the stream, parsed from [`Stream::raw_url`].
+    #[must_use]
+    pub fn platform(&self) -> StreamPlatform {
+        StreamPlatform::from_url(&self.raw_url)
+    }
+}
+
+/// A streaming platform.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum StreamPlatform {
+    Twitch,
+    YouTube,
+    Kick,
+    Other,
+}
+
+impl StreamPlatform {
+    /// Classifies a stream URL by its host.
+    ///
+    /// Returns [`StreamPlatform::Other`] for unknown hosts and invalid URLs.
+    #[must_use]
+    pub fn from_url(url: &str) -> Self {
+        let Ok(url) = Url::parse(url) else {
+            return Self::Other;
+        };
+        let Some(host) = url.host_str() else {
+            return Self::Other;
+        };
+
+        let is = |domain: &str| {
+            host.strip_suffix(domain)
+                .is_some_and(|rest| rest.is_empty() || rest.ends_with('.'))
+        };
+        if is("twitch.tv") {
+            Self::Twitch
+        } else if is("youtube.com") || is("youtu.be") {
+            Self::YouTube
+        } else if is("kick.com") {
+            Self::Kick
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Criteria used to pick a single stream out of a match's streams.
+///
+/// Streams are ranked by, in order:
+/// 1. official streams first,
+/// 2. the position of their language in [`languages`](Self::languages),
+///    streams in other languages coming last,
+/// 3. main streams first,
+/// 4. the position of their platform in [`platforms`](Self::platforms),
+///    streams on other platforms coming last,
+/// 5. their position in the stream list.
+///
+/// The default preference picks the official main stream, whatever its language.
+///
+/// # Example
+/// ```rust
+/// use pandascore::model::stream::{StreamPlatform, StreamPreference};
+///
+/// // The official English main stream, else any official stream, else the first stream.
+/// let preference = StreamPreference::builder()
+///     .languages(vec!["en".into()])
+///     .platforms(vec![StreamPlatform::Twitch, StreamPlatform::YouTube])
+///     .build();
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, bon::Builder)]
+pub struct StreamPreference {
+    /// Preferred languages, most preferred first.
+    #[builder(default)]
+    pub languages: Vec<CompactString>,
+    /// Preferred platforms, most preferred first.
+    #[builder(default)]
+    pub platforms: Vec<StreamPlatform>,
+}
+
+impl StreamPreference {
+    /// Returns the preferred stream, `None` if there are no streams.
+    #[must_use]
+    pub fn select<'a>(&self, streams: &'a [Stream]) -> Option<&'a Stream> {
+        streams
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, stream)| {
+                let language = self
+                    .languages
+                    .iter()
+                    .position(|language| language.eq_ignore_ascii_case(&stream.language))
+                    .unwrap_or(self.languages.len());
+                let platform = self
+                    .platforms
+                    .iter()
+                    .position(|platform| *platform == stream.platform())
+                    .unwrap_or(self.platforms.len());
+                (!stream.official, language, !stream.main, platform, *index)
+            })
+            .map(|(_, stream)| stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(url: &str, language: &str, main: bool, official: bool) -> Stream {
+        Stream {
+            embed_url: None,
+            language: language.into(),
+            main,
+            official,
+            raw_url: url.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_platform() {
+        let platform = |url| StreamPlatform::from_url(url);
+        assert_eq!(
+            platform("https://www.twitch.tv/lck"),
+            StreamPlatform::Twitch
+        );
+        assert_eq!(platform("https://twitch.tv/lck"), StreamPlatform::Twitch);
+        assert_eq!(
+            platform("https://www.youtube.com/watch?v=abc"),
+            StreamPlatform::YouTube
+        );
+        assert_eq!(platform("https://youtu.be/abc"), StreamPlatform::YouTube);
+        assert_eq!(platform("https://kick.com/lck"), StreamPlatform::Kick);
+        assert_eq!(platform("https://nottwitch.tv/lck"), StreamPlatform::Other);
+        assert_eq!(platform("https://www.afreecatv.com"), StreamPlatform::Other);
+        assert_eq!(platform("not a url"), StreamPlatform::Other);
+    }
+
+    #[test]
+    fn test_select() {
+        let streams = [
+            stream("https://www.twitch.tv/caster", "en", false, false),
+            stream("https://www.youtube.com/@lck_fr", "fr", false, true),
+            stream("https://www.twitch.tv/lck_fr", "fr", true, true),
+            stream("https://www.youtube.com/@lck", "en", false, true),
+        ];
+        let french = StreamPreference::builder()
+            .languages(vec!["fr".into(), "en".into()])
+            .build();
+
+        // Official French main stream.
+        assert_eq!(french.select(&streams), Some(&streams[2]));
+
+        // Official streams win over the preferred language.
+        let korean = StreamPreference::builder()
+            .languages(vec!["ko".into()])
+            .build();
+        assert_eq!(korean.select(&streams), Some(&streams[2]));
+
+        // Platforms break ties between streams of the same language.
+        let youtube = StreamPreference::builder()
+            .languages(vec!["en".into()])
+            .platforms(vec![StreamPlatform::YouTube])
+            .build();
+        assert_eq!(youtube.select(&streams), Some(&streams[3]));
+
+        // Falls back to the first stream when none are official.
+        assert_eq!(
+            StreamPreference::default().select(&streams[..1]),
+            Some(&streams[0])
+        );
+        assert_eq!(StreamPreference::default().select(&[]), None);
+    }
+}
@@ -1,12 +1,12 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::{player::CompactPlayer, VideoGame};
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Team {
     #[serde(flatten)]
@@ -17,7 +17,7 @@ pub struct Team {
     pub players: Vec<CompactPlayer>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactTeam {
     pub acronym: Option<CompactString>,
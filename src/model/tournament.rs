@@ -1,7 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 use compact_str::CompactString;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::model::{
@@ -13,7 +13,7 @@ use crate::model::{
     VideoGame, Winner,
 };
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Tournament {
     #[serde(flatten)]
@@ -29,14 +29,14 @@ pub struct Tournament {
     pub video_game: VideoGame,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Roster {
     pub players: Vec<CompactPlayer>,
     pub team: CompactTeam,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CompactTournament {
     #[serde(with = "time::serde::iso8601::option")]
@@ -60,7 +60,7 @@ pub struct CompactTournament {
     pub winner: Option<Winner>,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Tier {
     Unranked,
@@ -71,6 +71,20 @@ pub enum Tier {
     S,
 }
 
+impl Tier {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unranked => "unranked",
+            Self::D => "d",
+            Self::C => "c",
+            Self::B => "b",
+            Self::A => "a",
+            Self::S => "s",
+        }
+    }
+}
+
 impl Deref for Tournament {
     type Target = CompactTournament;
 
@@ -103,14 +117,14 @@ impl From<Tournament> for CompactTournament {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "type", content = "rosters")]
 #[non_exhaustive]
 pub enum TournamentRosters {
     Team(Vec<Team>),
     Player(Vec<Player>),
 }
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
 #[non_exhaustive]
@@ -119,7 +133,7 @@ pub enum TournamentStanding {
     Group(GroupStanding),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct BracketStanding {
     pub last_match: CompactMatch,
@@ -127,7 +141,7 @@ pub struct BracketStanding {
     pub team: CompactTeam,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct GroupStanding {
     pub losses: u64,
@@ -0,0 +1,18 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Ability {
+    /// The slot of the ability, e.g. `ultimate`.
+    pub ability_type: CompactString,
+    /// ID of the agent having this ability.
+    pub agent_id: Option<u64>,
+    /// Cost of the ability, in credits.
+    pub creds: Option<u32>,
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+    #[serde(rename = "videogame_versions")]
+    pub video_game_versions: Vec<CompactString>,
+}
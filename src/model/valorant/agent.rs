@@ -0,0 +1,13 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Agent {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+    pub portrait_url: Option<String>,
+    #[serde(rename = "videogame_versions")]
+    pub video_game_versions: Vec<CompactString>,
+}
@@ -0,0 +1,12 @@
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Map {
+    pub id: u64,
+    pub image_url: Option<String>,
+    pub name: CompactString,
+    #[serde(rename = "videogame_versions")]
+    pub video_game_versions: Vec<CompactString>,
+}
@@ -0,0 +1,6 @@
+//! Models related to the game Valorant.
+
+pub mod ability;
+pub mod agent;
+pub mod map;
+pub mod weapon;
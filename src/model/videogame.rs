@@ -0,0 +1,187 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use compact_str::CompactString;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::model::Identifier;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VideoGame {
+    pub id: u64,
+    pub name: CompactString,
+    pub slug: VideoGameSlug,
+    pub current_version: Option<CompactString>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VideoGameVersion {
+    /// Whether this videogame version is current
+    pub current: bool,
+    pub name: CompactString,
+}
+
+/// The slug of a video game covered by the API.
+///
+/// Slugs not known to this crate are kept as [`VideoGameSlug::Other`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize)]
+#[serde(from = "CompactString")]
+#[non_exhaustive]
+pub enum VideoGameSlug {
+    LeagueOfLegends,
+    CounterStrike,
+    Dota2,
+    Overwatch,
+    Pubg,
+    RocketLeague,
+    CallOfDuty,
+    RainbowSix,
+    EaSportsFc,
+    Valorant,
+    KingOfGlory,
+    WildRift,
+    MobileLegends,
+    StarCraft2,
+    StarCraftBroodWar,
+    Other(CompactString),
+}
+
+impl VideoGameSlug {
+    /// All video games known to this crate.
+    ///
+    /// Each of them has a dedicated module in [`crate::endpoint`],
+    /// whose paths start with its [`VideoGameSlug::endpoint_prefix`],
+    /// e.g. `/csgo/matches` in [`crate::endpoint::csgo`] for [`VideoGameSlug::CounterStrike`].
+    pub const ALL: [Self; 15] = [
+        Self::LeagueOfLegends,
+        Self::CounterStrike,
+        Self::Dota2,
+        Self::Overwatch,
+        Self::Pubg,
+        Self::RocketLeague,
+        Self::CallOfDuty,
+        Self::RainbowSix,
+        Self::EaSportsFc,
+        Self::Valorant,
+        Self::KingOfGlory,
+        Self::WildRift,
+        Self::MobileLegends,
+        Self::StarCraft2,
+        Self::StarCraftBroodWar,
+    ];
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::LeagueOfLegends => "league-of-legends",
+            Self::CounterStrike => "cs-go",
+            Self::Dota2 => "dota-2",
+            Self::Overwatch => "ow",
+            Self::Pubg => "pubg",
+            Self::RocketLeague => "rl",
+            Self::CallOfDuty => "cod-mw",
+            Self::RainbowSix => "r6-siege",
+            Self::EaSportsFc => "fifa",
+            Self::Valorant => "valorant",
+            Self::KingOfGlory => "kog",
+            Self::WildRift => "lol-wild-rift",
+            Self::MobileLegends => "mlbb",
+            Self::StarCraft2 => "starcraft-2",
+            Self::StarCraftBroodWar => "starcraft-brood-war",
+            Self::Other(slug) => slug,
+        }
+    }
+
+    /// Returns the path prefix of the game-specific endpoints, e.g. `csgo` for `/csgo/matches`.
+    ///
+    /// Returns `None` for [`VideoGameSlug::Other`], as only the games in
+    /// [`VideoGameSlug::ALL`] have dedicated endpoints.
+    #[must_use]
+    pub const fn endpoint_prefix(&self) -> Option<&'static str> {
+        match self {
+            Self::LeagueOfLegends => Some("lol"),
+            Self::CounterStrike => Some("csgo"),
+            Self::Dota2 => Some("dota2"),
+            Self::Overwatch => Some("ow"),
+            Self::Pubg => Some("pubg"),
+            Self::RocketLeague => Some("rl"),
+            Self::CallOfDuty => Some("codmw"),
+            Self::RainbowSix => Some("r6siege"),
+            Self::EaSportsFc => Some("fifa"),
+            Self::Valorant => Some("valorant"),
+            Self::KingOfGlory => Some("kog"),
+            Self::WildRift => Some("lol-wild-rift"),
+            Self::MobileLegends => Some("mlbb"),
+            Self::StarCraft2 => Some("starcraft-2"),
+            Self::StarCraftBroodWar => Some("starcraft-brood-war"),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<&str> for VideoGameSlug {
+    fn from(slug: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|known| known.as_str() == slug)
+            .unwrap_or_else(|| Self::Other(slug.into()))
+    }
+}
+
+impl From<CompactString> for VideoGameSlug {
+    fn from(slug: CompactString) -> Self {
+        match Self::from(slug.as_str()) {
+            Self::Other(_) => Self::Other(slug),
+            known => known,
+        }
+    }
+}
+
+impl FromStr for VideoGameSlug {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl Display for VideoGameSlug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for VideoGameSlug {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a VideoGameSlug> for Identifier<'a> {
+    fn from(slug: &'a VideoGameSlug) -> Self {
+        Self::Slug(slug.as_str().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_round_trip() {
+        for slug in VideoGameSlug::ALL {
+            assert_eq!(VideoGameSlug::from(slug.as_str()), slug);
+        }
+        assert_eq!(
+            VideoGameSlug::from("new-game"),
+            VideoGameSlug::Other("new-game".into())
+        );
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt::Formatter;
 
 use serde::{
     de::{Error, MapAccess, Visitor},
-    Deserialize, Deserializer,
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 use serde_json::Value;
 
@@ -40,6 +41,8 @@ use crate::model::{player::CompactPlayer, team::CompactTeam};
 ///
 /// The `Winner` object is a Rust enum that can represent all of these cases
 /// and attempts to deserialize the various representations into a single enum.
+/// It is always serialized using the second representation,
+/// omitting `winner` if the team or player is not known.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Winner {
@@ -71,6 +74,34 @@ impl<'de> Deserialize<'de> for Winner {
     }
 }
 
+impl Serialize for Winner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Winner", 3)?;
+        match self {
+            Self::Team { id, team } => {
+                match team {
+                    Some(team) => state.serialize_field("winner", team)?,
+                    None => state.skip_field("winner")?,
+                }
+                state.serialize_field("winner_id", id)?;
+                state.serialize_field("winner_type", &WinnerType::Team)?;
+            }
+            Self::Player { id, player } => {
+                match player {
+                    Some(player) => state.serialize_field("winner", player)?,
+                    None => state.skip_field("winner")?,
+                }
+                state.serialize_field("winner_id", id)?;
+                state.serialize_field("winner_type", &WinnerType::Player)?;
+            }
+        }
+        state.end()
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum WinnerFields {
@@ -181,7 +212,7 @@ struct WinnerInternal {
     r#type: WinnerType,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
 enum WinnerType {
     Team,
     Player,
@@ -191,14 +222,14 @@ enum WinnerType {
 mod tests {
     use super::*;
 
-    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    #[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
     struct TestStruct {
         extra: i32,
         #[serde(flatten)]
         winner: Winner,
     }
 
-    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    #[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
     struct TestStructOption {
         extra: i32,
         #[serde(flatten)]
@@ -347,4 +378,47 @@ mod tests {
             test
         );
     }
+
+    #[test]
+    fn test_winner_serialize() {
+        let test = TestStruct {
+            extra: 42,
+            winner: Winner::Player {
+                id: Some(1),
+                player: None,
+            },
+        };
+        let json = serde_json::to_string(&test).unwrap();
+        assert_eq!(json, r#"{"extra":42,"winner_id":1,"winner_type":"Player"}"#);
+        assert_eq!(serde_json::from_str::<TestStruct>(&json).unwrap(), test);
+
+        let test = TestStruct {
+            extra: 42,
+            winner: Winner::Team {
+                id: Some(1),
+                team: Some(Box::new(CompactTeam {
+                    acronym: None,
+                    id: 1,
+                    image_url: None,
+                    location: None,
+                    modified_at: time::OffsetDateTime::from_unix_timestamp(0).unwrap(),
+                    name: "T1".into(),
+                    slug: None,
+                })),
+            },
+        };
+        let json = serde_json::to_string(&test).unwrap();
+        assert_eq!(serde_json::from_str::<TestStruct>(&json).unwrap(), test);
+
+        let test = TestStructOption {
+            extra: 42,
+            winner: None,
+        };
+        let json = serde_json::to_string(&test).unwrap();
+        assert_eq!(json, r#"{"extra":42}"#);
+        assert_eq!(
+            serde_json::from_str::<TestStructOption>(&json).unwrap(),
+            test
+        );
+    }
 }
@@ -0,0 +1,155 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    io::ErrorKind,
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::model::incident::{Incident, IncidentType};
+
+/// The position of a [`Syncer`](super::Syncer) in the incident feed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Checkpoint {
+    /// Modification time of the latest incident seen.
+    #[serde(with = "time::serde::iso8601")]
+    pub modified_at: OffsetDateTime,
+    /// Incidents seen at exactly `modified_at`,
+    /// used to avoid emitting them again on the next poll.
+    pub seen: Vec<(IncidentType, u64)>,
+}
+
+impl Checkpoint {
+    /// Creates a checkpoint starting at the given time.
+    #[must_use]
+    pub const fn new(modified_at: OffsetDateTime) -> Self {
+        Self {
+            modified_at,
+            seen: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the incident was already seen by this checkpoint.
+    #[must_use]
+    pub fn contains(&self, incident: &Incident) -> bool {
+        incident.modified_at < self.modified_at
+            || (incident.modified_at == self.modified_at
+                && self.seen.contains(&(incident.r#type, incident.id)))
+    }
+
+    /// Advances the checkpoint past the given incident.
+    pub fn advance(&mut self, incident: &Incident) {
+        if incident.modified_at > self.modified_at {
+            self.modified_at = incident.modified_at;
+            self.seen.clear();
+        }
+        if incident.modified_at == self.modified_at {
+            self.seen.push((incident.r#type, incident.id));
+        }
+    }
+}
+
+/// Persistent storage for a [`Checkpoint`],
+/// allowing a [`Syncer`](super::Syncer) to resume after a restart.
+pub trait CheckpointStore {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Loads the stored checkpoint, returning `None` if none was saved yet.
+    fn load(&self) -> impl Future<Output = Result<Option<Checkpoint>, Self::Error>> + Send;
+
+    /// Saves the checkpoint, replacing any previously stored checkpoint.
+    fn save(&self, checkpoint: &Checkpoint)
+        -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// A [`CheckpointStore`] keeping the checkpoint in memory.
+///
+/// Clones of the store share the same checkpoint.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: Arc<Mutex<Option<Checkpoint>>>,
+}
+
+impl MemoryCheckpointStore {
+    /// Creates a new empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the currently stored checkpoint.
+    #[must_use]
+    pub fn get(&self) -> Option<Checkpoint> {
+        self.checkpoint
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    type Error = Infallible;
+
+    async fn load(&self) -> Result<Option<Checkpoint>, Self::Error> {
+        Ok(self.get())
+    }
+
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<(), Self::Error> {
+        *self
+            .checkpoint
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// A [`CheckpointStore`] persisting the checkpoint as a JSON file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct JsonFileCheckpointStore {
+    path: PathBuf,
+}
+
+impl JsonFileCheckpointStore {
+    /// Creates a store backed by the file at the given path.
+    /// The file is created on the first save.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+/// An error that occurred while loading or saving a [`JsonFileCheckpointStore`].
+#[derive(Debug, thiserror::Error)]
+pub enum JsonFileCheckpointError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl CheckpointStore for JsonFileCheckpointStore {
+    type Error = JsonFileCheckpointError;
+
+    async fn load(&self) -> Result<Option<Checkpoint>, Self::Error> {
+        match tokio::fs::read(&self.path).await {
+            Ok(contents) => Ok(Some(serde_json::from_slice(&contents)?)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<(), Self::Error> {
+        let contents = serde_json::to_vec(checkpoint)?;
+
+        // Write to a temporary file first so a crash can't leave a truncated checkpoint.
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        tokio::fs::write(&tmp, contents).await?;
+        tokio::fs::rename(&tmp, &self.path).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+//! Incremental synchronization driven by incidents.
+//!
+//! A [`Syncer`] polls the [`ListIncidents`] endpoint,
+//! emitting a [`SyncEvent`] for every entity added, changed or deleted since the last poll.
+//! Its position is persisted through a [`CheckpointStore`],
+//! so it can resume where it left off after a restart.
+//!
+//! # Example
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use pandascore::{
+//!     sync::{JsonFileCheckpointStore, SyncEvent, Syncer},
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let client = Client::new(reqwest::Client::new(), "token").unwrap();
+//! let syncer = Syncer::builder()
+//!     .client(client)
+//!     .store(JsonFileCheckpointStore::new("checkpoint.json"))
+//!     .build();
+//!
+//! let mut events = std::pin::pin!(syncer.stream());
+//! while let Some(event) = events.next().await {
+//!     match event.unwrap() {
+//!         SyncEvent::Added(object) => println!("added: {object:?}"),
+//!         SyncEvent::Changed(object) => println!("changed: {object:?}"),
+//!         SyncEvent::Deleted { r#type, id, .. } => println!("deleted: {type:?} {id}"),
+//!     }
+//! }
+//! # }
+//! ```
+
+mod checkpoint;
+
+use std::{collections::VecDeque, time::Duration};
+
+pub use checkpoint::{
+    Checkpoint, CheckpointStore, JsonFileCheckpointError, JsonFileCheckpointStore,
+    MemoryCheckpointStore,
+};
+use futures::{stream, Stream, TryStreamExt};
+use time::OffsetDateTime;
+
+use crate::{
+    endpoint::{all::incidents::ListIncidents, CollectionOptions, EndpointError, PaginationLimits},
+    model::incident::{ChangeType, Deletion, Incident, IncidentObject, IncidentType},
+    Client, ClientTransport,
+};
+
+/// A change to an entity, emitted by a [`Syncer`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum SyncEvent {
+    /// A new entity was added.
+    Added(IncidentObject),
+    /// An existing entity was changed.
+    Changed(IncidentObject),
+    /// An entity was deleted.
+    Deleted {
+        r#type: IncidentType,
+        id: u64,
+        deletion: Deletion,
+    },
+}
+
+impl From<Incident> for SyncEvent {
+    fn from(incident: Incident) -> Self {
+        match (incident.change_type, incident.object) {
+            (_, IncidentObject::Deleted(deletion)) => Self::Deleted {
+                r#type: incident.r#type,
+                id: incident.id,
+                deletion,
+            },
+            (ChangeType::Creation, object) => Self::Added(object),
+            (_, object) => Self::Changed(object),
+        }
+    }
+}
+
+/// An error that occurred while synchronizing.
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError<E> {
+    #[error(transparent)]
+    Endpoint(#[from] EndpointError),
+    #[error("Checkpoint store error: {0}")]
+    Checkpoint(#[source] E),
+}
+
+/// Polls incidents and emits the changes since the last checkpoint.
+#[derive(Debug, bon::Builder)]
+pub struct Syncer<T, S> {
+    client: Client<T>,
+    store: S,
+    /// Delay between polls when streaming.
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+    /// Only synchronize the given entity types. Synchronizes all types if empty.
+    #[builder(default)]
+    types: Vec<IncidentType>,
+    /// Where to start when no checkpoint is stored.
+    /// Starts with the latest page of incidents returned by the API if `None`,
+    /// instead of retrieving the whole history.
+    start: Option<OffsetDateTime>,
+    #[builder(skip)]
+    checkpoint: Option<Checkpoint>,
+}
+
+#[allow(clippy::future_not_send)]
+impl<T: ClientTransport, S: CheckpointStore> Syncer<T, S> {
+    /// Returns the current checkpoint, if any incident was seen or a checkpoint was loaded.
+    #[must_use]
+    pub const fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Polls incidents once, returning the changes since the last checkpoint
+    /// in chronological order.
+    ///
+    /// The checkpoint is loaded from the store on the first poll
+    /// and saved after every poll that returned new incidents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the incidents can't be retrieved,
+    /// or the checkpoint can't be loaded or saved.
+    pub async fn poll(&mut self) -> Result<Vec<SyncEvent>, SyncError<S::Error>> {
+        if self.checkpoint.is_none() {
+            self.checkpoint = self.store.load().await.map_err(SyncError::Checkpoint)?;
+        }
+
+        let since = self
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.modified_at)
+            .or(self.start);
+        let endpoint = ListIncidents::builder()
+            .types(self.types.clone())
+            .maybe_since(since)
+            .options(CollectionOptions::new().per_page(100))
+            .build();
+        let limits = if since.is_none() {
+            PaginationLimits::builder().max_pages(1).build()
+        } else {
+            PaginationLimits::default()
+        };
+        let mut incidents: Vec<Incident> = self
+            .client
+            .paginate_with(endpoint, limits)
+            .try_collect()
+            .await?;
+        incidents.sort_by_key(|incident| (incident.modified_at, incident.r#type, incident.id));
+
+        let mut checkpoint = self.checkpoint.clone();
+        let mut events = Vec::new();
+        for incident in incidents {
+            if checkpoint.as_ref().is_some_and(|c| c.contains(&incident)) {
+                continue;
+            }
+            checkpoint
+                .get_or_insert_with(|| Checkpoint::new(incident.modified_at))
+                .advance(&incident);
+            events.push(SyncEvent::from(incident));
+        }
+
+        if checkpoint != self.checkpoint {
+            if let Some(checkpoint) = &checkpoint {
+                self.store
+                    .save(checkpoint)
+                    .await
+                    .map_err(SyncError::Checkpoint)?;
+            }
+            self.checkpoint = checkpoint;
+        }
+
+        Ok(events)
+    }
+
+    /// Polls incidents forever, waiting for the configured interval between polls.
+    ///
+    /// Errors are yielded without ending the stream,
+    /// the next poll resumes from the last saved checkpoint.
+    pub fn stream(self) -> impl Stream<Item = Result<SyncEvent, SyncError<S::Error>>> {
+        stream::unfold(
+            (self, VecDeque::new(), false),
+            |(mut syncer, mut buffer, mut polled)| async move {
+                loop {
+                    if let Some(event) = buffer.pop_front() {
+                        return Some((Ok(event), (syncer, buffer, polled)));
+                    }
+                    if polled {
+                        tokio::time::sleep(syncer.interval).await;
+                    }
+                    polled = true;
+
+                    match syncer.poll().await {
+                        Ok(events) => buffer.extend(events),
+                        Err(e) => return Some((Err(e), (syncer, buffer, polled))),
+                    }
+                }
+            },
+        )
+    }
+}
@@ -1,6 +1,10 @@
 #![allow(dead_code)]
 use std::{
     future::Ready,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
 };
 
@@ -11,15 +15,24 @@ use tower::Service;
 #[derive(Debug, Clone)]
 pub struct MockClient {
     expectations: Vec<Expectation>,
+    headers: Vec<(&'static str, &'static str)>,
+    status: StatusCode,
     response: &'static [u8],
+    /// Responses returned before `response`, in order.
+    failures: Vec<(StatusCode, &'static [(&'static str, &'static str)])>,
+    calls: Arc<AtomicUsize>,
 }
 
 impl MockClient {
     #[must_use]
-    pub const fn new(response: &'static [u8]) -> Self {
+    pub fn new(response: &'static [u8]) -> Self {
         Self {
             expectations: Vec::new(),
+            headers: Vec::new(),
+            status: StatusCode::OK,
             response,
+            failures: Vec::new(),
+            calls: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -28,6 +41,35 @@ impl MockClient {
         self.expectations.push(expectation);
         self
     }
+
+    #[must_use]
+    pub const fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub fn header(mut self, key: &'static str, value: &'static str) -> Self {
+        self.headers.push((key, value));
+        self
+    }
+
+    /// Responds with an empty body and the given status and headers before the real response.
+    #[must_use]
+    pub fn fail(
+        mut self,
+        status: StatusCode,
+        headers: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        self.failures.push((status, headers));
+        self
+    }
+
+    /// Number of requests received, shared between clones.
+    #[must_use]
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
 }
 
 impl Service<Request> for MockClient {
@@ -44,10 +86,21 @@ impl Service<Request> for MockClient {
             expectation.validate(&req);
         }
 
-        let response = http::Response::builder()
-            .status(StatusCode::OK)
-            .body(self.response)
-            .unwrap();
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if let Some((status, headers)) = self.failures.get(call) {
+            let mut response = http::Response::builder().status(*status);
+            for (key, value) in *headers {
+                response = response.header(*key, *value);
+            }
+            let response = response.body(&b""[..]).unwrap();
+            return std::future::ready(Ok(response.into()));
+        }
+
+        let mut response = http::Response::builder().status(self.status);
+        for (key, value) in &self.headers {
+            response = response.header(*key, *value);
+        }
+        let response = response.body(self.response).unwrap();
 
         std::future::ready(Ok(response.into()))
     }
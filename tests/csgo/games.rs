@@ -0,0 +1,26 @@
+use pandascore::{
+    endpoint::csgo::games::ListGameRounds,
+    model::csgo::round::{RoundOutcome, Side},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_game_rounds() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/rounds_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/games/54321/rounds"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = ListGameRounds::builder().game_id(54321).build();
+    let rounds = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(rounds.len(), 3);
+    assert_eq!(rounds[0].round, 1);
+    assert_eq!(rounds[0].outcome, RoundOutcome::Eliminated);
+    assert_eq!(rounds[0].winner_side(), Some(Side::CounterTerrorists));
+    assert_eq!(rounds[1].outcome, RoundOutcome::Exploded);
+    assert_eq!(rounds[1].winner_side(), Some(Side::Terrorists));
+}
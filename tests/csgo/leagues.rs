@@ -0,0 +1,20 @@
+use pandascore::{endpoint::csgo::leagues::ListLeagues, model::VideoGameSlug, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_leagues() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/leagues_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/leagues"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let items = client.execute(ListLeagues::default()).await.unwrap();
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].id, 4218);
+    assert_eq!(items[0].name, "BLAST Premier");
+    assert_eq!(items[0].series.len(), 1);
+    assert_eq!(items[0].video_game.slug, VideoGameSlug::CounterStrike);
+}
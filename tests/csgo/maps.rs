@@ -0,0 +1,49 @@
+use pandascore::{
+    endpoint::csgo::maps::{GetMap, ListMaps},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_maps() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/maps_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/maps"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let maps = client.execute(ListMaps::default()).await.unwrap();
+
+    assert_eq!(maps.len(), 3);
+    assert_eq!(maps[0].id, 31);
+    assert_eq!(maps[0].name, "Ancient");
+    assert_eq!(maps[2].image_url, None);
+}
+
+#[tokio::test]
+async fn test_get_map() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/maps_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/maps/1"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let map = client.execute(GetMap::from(1)).await.unwrap();
+
+    assert_eq!(map.id, 1);
+    assert_eq!(map.name, "Dust2");
+}
+
+#[tokio::test]
+async fn test_get_map_by_slug() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/maps_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/maps/dust2"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let map = client.execute(GetMap::from("dust2")).await.unwrap();
+
+    assert_eq!(map.name, "Dust2");
+}
@@ -0,0 +1,37 @@
+use pandascore::{
+    endpoint::csgo::weapons::{GetWeapon, ListWeapons},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_weapons() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/weapons_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/weapons"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let weapons = client.execute(ListWeapons::default()).await.unwrap();
+
+    assert_eq!(weapons.len(), 2);
+    assert_eq!(weapons[0].name, "AK-47");
+    assert_eq!(weapons[0].kind, "Rifle");
+    assert_eq!(weapons[1].name, "AWP");
+}
+
+#[tokio::test]
+async fn test_get_weapon() {
+    let client = MockClient::new(include_bytes!("../fixtures/csgo/weapons_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/csgo/weapons/12"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let weapon = client.execute(GetWeapon::from(12)).await.unwrap();
+
+    assert_eq!(weapon.id, 12);
+    assert_eq!(weapon.name, "AWP");
+    assert_eq!(weapon.kind, "Sniper");
+}
@@ -0,0 +1,18 @@
+use pandascore::{endpoint::dota2::abilities::ListAbilities, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_abilities() {
+    let client = MockClient::new(include_bytes!("../fixtures/dota2/abilities_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/dota2/abilities"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let abilities = client.execute(ListAbilities::default()).await.unwrap();
+
+    assert_eq!(abilities.len(), 2);
+    assert_eq!(abilities[0].id, 5003);
+    assert_eq!(abilities[1].name, "antimage_blink");
+}
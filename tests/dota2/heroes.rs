@@ -0,0 +1,42 @@
+use pandascore::{
+    endpoint::dota2::heroes::{GetHero, ListHeroes},
+    model::dota2::hero::Attribute,
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_heroes() {
+    let client = MockClient::new(include_bytes!("../fixtures/dota2/heroes_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/dota2/heroes"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let heroes = client.execute(ListHeroes::default()).await.unwrap();
+
+    assert_eq!(heroes.len(), 2);
+    assert_eq!(heroes[0].localized_name, "Anti-Mage");
+    assert_eq!(heroes[0].primary_attribute, Some(Attribute::Agility));
+    assert_eq!(heroes[0].agility_per_level, Some(2.8));
+    // Stats aren't returned for every hero.
+    assert_eq!(heroes[1].name, "npc_dota_hero_axe");
+    assert_eq!(heroes[1].base_strength, None);
+}
+
+#[tokio::test]
+async fn test_get_hero() {
+    let client = MockClient::new(include_bytes!("../fixtures/dota2/heroes_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/dota2/heroes/129"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let hero = client.execute(GetHero::from(129)).await.unwrap();
+
+    assert_eq!(hero.id, 129);
+    assert_eq!(hero.localized_name, "Mars");
+    assert_eq!(hero.primary_attribute, Some(Attribute::Strength));
+    assert_eq!(hero.strength_per_level, Some(3.2));
+}
@@ -0,0 +1,19 @@
+use pandascore::{endpoint::dota2::items::ListItems, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_items() {
+    let client = MockClient::new(include_bytes!("../fixtures/dota2/items_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/dota2/items"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let items = client.execute(ListItems::default()).await.unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].name, "item_blink");
+    assert_eq!(items[1].id, 116);
+    assert_eq!(items[1].image_url, None);
+}
@@ -0,0 +1,101 @@
+use http::StatusCode;
+use pandascore::{
+    endpoint::{
+        all::{leagues::ListLeagues, players::GetPlayer},
+        CollectionOptions, EndpointError,
+    },
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+#[tokio::test]
+async fn test_not_found() {
+    let client = MockClient::new(br#"{"error":"Not Found"}"#)
+        .status(StatusCode::NOT_FOUND)
+        .expect(Expectation::Path("/players/unknown"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let err = client
+        .execute(GetPlayer::from("unknown"))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_not_found());
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    let EndpointError::NotFound(error) = err else {
+        unreachable!()
+    };
+    assert_eq!(error.error.as_deref(), Some("Not Found"));
+    assert_eq!(error.message, None);
+}
+
+#[tokio::test]
+async fn test_forbidden_by_plan() {
+    let client = MockClient::new(
+        br#"{"error":"Forbidden","message":"Access to this endpoint is not included in your plan"}"#,
+    )
+    .status(StatusCode::FORBIDDEN);
+
+    let client = Client::new(client, "").unwrap();
+
+    let err = client.execute(ListLeagues::default()).await.unwrap_err();
+
+    assert!(err.is_forbidden_by_plan());
+    let error = err.api_error().unwrap();
+    assert_eq!(error.status, StatusCode::FORBIDDEN);
+    assert_eq!(
+        error.message.as_deref(),
+        Some("Access to this endpoint is not included in your plan")
+    );
+}
+
+#[tokio::test]
+async fn test_unauthorized() {
+    let client = MockClient::new(br#"{"error":"Token is missing or invalid"}"#)
+        .status(StatusCode::UNAUTHORIZED);
+
+    let client = Client::new(client, "").unwrap();
+
+    let err = client.execute(GetPlayer::from(1)).await.unwrap_err();
+
+    assert!(err.is_unauthorized());
+    assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_unprocessable_entity() {
+    let client = MockClient::new(br#"{"error":"Unprocessable Entity","message":"Invalid filter"}"#)
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .expect(Expectation::Query("filter[foo]", "bar"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let list_leagues = ListLeagues(CollectionOptions::new().filter("foo", "bar"));
+    let err = client.execute(list_leagues).await.unwrap_err();
+
+    assert!(err.is_unprocessable());
+    assert_eq!(
+        err.api_error().unwrap().message.as_deref(),
+        Some("Invalid filter")
+    );
+}
+
+#[tokio::test]
+async fn test_non_json_error_body() {
+    let client = MockClient::new(b"Bad Gateway").status(StatusCode::BAD_GATEWAY);
+
+    let client = Client::new(client, "").unwrap();
+
+    let err = client.execute(GetPlayer::from(1)).await.unwrap_err();
+
+    let EndpointError::Api(error) = err else {
+        unreachable!()
+    };
+    assert_eq!(error.status, StatusCode::BAD_GATEWAY);
+    assert_eq!(error.error, None);
+    assert_eq!(error.message, None);
+}
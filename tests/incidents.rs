@@ -0,0 +1,71 @@
+use pandascore::{
+    endpoint::all::incidents::{ListChanges, ListDeletions, ListIncidents},
+    model::incident::{ChangeType, IncidentObject, IncidentType},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+#[tokio::test]
+async fn test_list_incidents() {
+    let client = MockClient::new(include_bytes!("./fixtures/incidents_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/incidents"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let response = client.execute(ListIncidents::default()).await.unwrap();
+
+    assert_eq!(response.len(), 4);
+
+    assert_eq!(response[0].change_type, ChangeType::Update);
+    assert_eq!(response[0].r#type, IncidentType::Match);
+    let IncidentObject::Match(m) = &response[0].object else {
+        panic!("expected match, got {:?}", response[0].object);
+    };
+    assert_eq!(m.id, 1_000_450);
+
+    assert_eq!(response[1].change_type, ChangeType::Creation);
+    let IncidentObject::Team(team) = &response[1].object else {
+        panic!("expected team, got {:?}", response[1].object);
+    };
+    assert_eq!(team.name, "VGJ.Thunder");
+
+    assert!(matches!(response[2].object, IncidentObject::League(_)));
+
+    assert_eq!(response[3].change_type, ChangeType::Deletion);
+    assert_eq!(response[3].r#type, IncidentType::Tournament);
+    let IncidentObject::Deleted(deletion) = &response[3].object else {
+        panic!("expected deletion, got {:?}", response[3].object);
+    };
+    assert_eq!(deletion.reason.as_deref(), Some("Duplicate"));
+}
+
+#[tokio::test]
+async fn test_list_changes_filtered() {
+    let client = MockClient::new(b"[]")
+        .expect(Expectation::Path("/changes"))
+        .expect(Expectation::Query("type", "team,player"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let list_changes = ListChanges::builder()
+        .types(vec![IncidentType::Team, IncidentType::Player])
+        .build();
+    let response = client.execute(list_changes).await.unwrap();
+
+    assert!(response.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_deletions() {
+    let client = MockClient::new(b"[]").expect(Expectation::Path("/deletions"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let response = client.execute(ListDeletions::default()).await.unwrap();
+
+    assert!(response.is_empty());
+}
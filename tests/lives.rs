@@ -0,0 +1,36 @@
+use pandascore::{
+    endpoint::all::lives::ListLives,
+    model::{live::LiveEndpointType, matches::MatchStatus},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+#[tokio::test]
+async fn test_list_lives() {
+    let client = MockClient::new(include_bytes!("./fixtures/lives_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/lives"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let response = client.execute(ListLives::default()).await.unwrap();
+
+    assert_eq!(response.len(), 1);
+    let live = &response[0];
+    assert!(live.is_supported());
+    assert!(live.opens_at().is_some());
+    assert_eq!(live.r#match.id, 590_910);
+    assert_eq!(live.r#match.status, MatchStatus::Running);
+    assert_eq!(live.event.game, "rl");
+    assert!(live.event.is_active);
+    assert_eq!(live.endpoints.len(), 2);
+    assert_eq!(live.endpoints[0].r#type, LiveEndpointType::Frames);
+    assert_eq!(live.endpoints[1].r#type, LiveEndpointType::Events);
+    assert!(live
+        .endpoints
+        .iter()
+        .all(|e| e.match_id == 590_910 && e.open));
+}
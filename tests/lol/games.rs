@@ -0,0 +1,70 @@
+use pandascore::{
+    endpoint::lol::games::{GetGame, ListMatchGames},
+    model::{
+        game::GameStatus,
+        lol::game::{Role, Side},
+        Winner,
+    },
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_get_game() {
+    let client = MockClient::new(include_bytes!("../fixtures/lol/games_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/lol/games/259678"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let game = client.execute(GetGame(259_678)).await.unwrap();
+
+    assert_eq!(game.id, 259_678);
+    assert_eq!(game.status, GameStatus::Finished);
+    assert_eq!(
+        game.winner,
+        Some(Winner::Team {
+            id: Some(2883),
+            team: None
+        })
+    );
+
+    let blue = game.team(Side::Blue).unwrap();
+    assert_eq!(blue.team.acronym.as_deref(), Some("T1"));
+    assert_eq!(blue.bans, [2711, 2569, 2600]);
+    assert_eq!(blue.first_blood, Some(true));
+    assert_eq!(blue.objectives.herald_kills, Some(1));
+
+    let red = game.team(Side::Red).unwrap();
+    assert_eq!(red.objectives.dragon_kills, Some(4));
+    assert_eq!(red.objectives.baron_kills, Some(2));
+    assert_eq!(red.objectives.tower_kills, Some(10));
+    assert_eq!(red.gold_earned, Some(79_310));
+
+    let faker = game.players_of(126_061).next().unwrap();
+    assert_eq!(faker.player.name, "Faker");
+    assert_eq!(faker.role, Some(Role::Mid));
+    assert_eq!(faker.champion.name, "Azir");
+    assert_eq!(faker.items.len(), 1);
+    assert!((faker.kda() - 5.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_list_match_games() {
+    let client = MockClient::new(include_bytes!("../fixtures/lol/match_games_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/lol/matches/1000450/games"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = ListMatchGames::builder().match_id(1_000_450).build();
+    let games = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].position, 1);
+    assert_eq!(games[1].status, GameStatus::NotStarted);
+    assert_eq!(games[1].winner, None);
+    assert!(games[1].players.is_empty());
+    assert_eq!(games[1].teams[0].objectives.dragon_kills, None);
+}
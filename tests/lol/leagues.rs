@@ -1,4 +1,4 @@
-use pandascore::{endpoint::lol::leagues::ListLeagues, Client};
+use pandascore::{endpoint::lol::leagues::ListLeagues, model::VideoGameSlug, Client};
 
 use crate::common::{Expectation, MockClient};
 
@@ -21,5 +21,5 @@ async fn test_list_leagues() {
     );
     assert_eq!(items[0].series.len(), 1);
     assert_eq!(items[0].video_game.name, "LoL");
-    assert_eq!(items[0].video_game.slug, "league-of-legends");
+    assert_eq!(items[0].video_game.slug, VideoGameSlug::LeagueOfLegends);
 }
@@ -0,0 +1,56 @@
+use pandascore::{
+    endpoint::lol::stats::{GetPlayerStats, GetTeamStats, StatsScope},
+    model::Identifier,
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_get_player_stats() {
+    let client = MockClient::new(include_bytes!("../fixtures/lol/player_stats_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/lol/players/faker/stats"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = GetPlayerStats::builder().id("faker").build();
+    let player = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(player.id, 585);
+    assert_eq!(player.name, "Faker");
+    assert_eq!(player.stats.games_count, 40);
+    assert_eq!(player.stats.totals.games_won, 26);
+    assert_eq!(player.stats.win_rate(), Some(0.65));
+    assert_eq!(player.stats.averages.creep_score_at_14, Some(121.4));
+    assert!((player.stats.averages.kda().unwrap() - 4.166_666).abs() < 1e-3);
+
+    let azir = &player.stats.favorite_champions[0];
+    assert_eq!(azir.champion.name, "Azir");
+    assert_eq!(azir.win_rate(), Some(8.0 / 12.0));
+}
+
+#[tokio::test]
+async fn test_get_tournament_team_stats() {
+    let client = MockClient::new(include_bytes!("../fixtures/lol/team_stats_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path(
+            "/lol/tournaments/14032/teams/126061/stats",
+        ));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = GetTeamStats::builder()
+        .id(126_061)
+        .scope(StatsScope::Tournament(Identifier::Id(14032)))
+        .build();
+    let team = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(team.acronym.as_deref(), Some("T1"));
+    assert_eq!(team.stats.win_rate(), Some(0.65));
+    assert_eq!(team.stats.averages.game_length, Some(1895.5));
+    assert_eq!(team.stats.totals.tower_kills, Some(268));
+    assert_eq!(team.stats.most_banned_champions[0].wins, None);
+    assert_eq!(team.stats.most_banned_champions[0].win_rate(), None);
+    assert_eq!(team.stats.most_picked_champions.len(), 1);
+}
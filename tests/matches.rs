@@ -2,7 +2,9 @@ use common::{Expectation, MockClient};
 use pandascore::{
     endpoint::all::matches::GetMatch,
     model::{
-        matches::{Match, MatchStatus, MatchType},
+        game::GameStatus,
+        matches::{CompactMatchOpponent, Match, MatchStatus, MatchType},
+        stream::{StreamPlatform, StreamPreference},
         Identifier, Winner,
     },
     Client,
@@ -41,3 +43,63 @@ async fn test_get_match() {
     assert_eq!(response.status, MatchStatus::Finished);
     assert!(matches!(response.winner, Some(Winner::Team { .. })))
 }
+
+#[test]
+fn test_deserialize_match_details() {
+    let json = include_str!("./fixtures/match_get.json");
+    let m: Match = serde_json::from_str(json).unwrap();
+
+    assert_eq!(m.games.len(), 5);
+    assert_eq!(m.games[0].id, 259_678);
+    assert_eq!(m.games[0].status, GameStatus::Finished);
+    assert_eq!(
+        m.games[0].winner,
+        Some(Winner::Team {
+            id: Some(2883),
+            team: None
+        })
+    );
+
+    assert_eq!(m.opponents.len(), 2);
+    let CompactMatchOpponent::Team(team) = &m.opponents[0] else {
+        panic!("expected team, got {:?}", m.opponents[0]);
+    };
+    assert_eq!(team.acronym.as_deref(), Some("GEN"));
+
+    assert_eq!(m.streams_list.len(), 3);
+    let main = m.streams_list.iter().find(|stream| stream.main).unwrap();
+    assert_eq!(main.language, "en");
+    assert!(main.official);
+    assert_eq!(main.raw_url, "https://www.twitch.tv/lck");
+    assert_eq!(
+        main.embed_url.as_deref(),
+        Some("https://player.twitch.tv/?channel=lck")
+    );
+
+    let Some(Winner::Team {
+        id,
+        team: Some(team),
+    }) = &m.winner
+    else {
+        panic!("expected winner team, got {:?}", m.winner);
+    };
+    assert_eq!(*id, Some(2883));
+    assert_eq!(team.acronym.as_deref(), Some("HLE"));
+}
+
+#[test]
+fn test_preferred_stream() {
+    let json = include_str!("./fixtures/match_get.json");
+    let m: Match = serde_json::from_str(json).unwrap();
+
+    let stream = m.preferred_stream(&StreamPreference::default()).unwrap();
+    assert_eq!(stream.raw_url, "https://www.twitch.tv/lck");
+    assert_eq!(stream.platform(), StreamPlatform::Twitch);
+
+    // The official stream is preferred over the requested language.
+    let french = StreamPreference::builder()
+        .languages(vec!["fr".into()])
+        .build();
+    let stream = m.preferred_stream(&french).unwrap();
+    assert_eq!(stream.language, "en");
+}
@@ -0,0 +1,41 @@
+use pandascore::{
+    endpoint::ow::heroes::{GetHero, ListHeroes},
+    model::ow::hero::HeroRole,
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_heroes() {
+    let client = MockClient::new(include_bytes!("../fixtures/ow/heroes_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/ow/heroes"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let heroes = client.execute(ListHeroes::default()).await.unwrap();
+
+    assert_eq!(heroes.len(), 2);
+    assert_eq!(heroes[0].name, "Ana");
+    assert_eq!(heroes[0].role, HeroRole::Support);
+    assert_eq!(heroes[0].real_name.as_deref(), Some("Ana Amari"));
+    assert_eq!(heroes[1].role, HeroRole::Other);
+}
+
+#[tokio::test]
+async fn test_get_hero() {
+    let client = MockClient::new(include_bytes!("../fixtures/ow/heroes_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/ow/heroes/wrecking-ball"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let hero = client
+        .execute(GetHero::from("wrecking-ball"))
+        .await
+        .unwrap();
+
+    assert_eq!(hero.id, 28);
+    assert_eq!(hero.role, HeroRole::Tank);
+}
@@ -0,0 +1,19 @@
+use pandascore::{endpoint::ow::maps::ListMaps, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_maps() {
+    let client = MockClient::new(include_bytes!("../fixtures/ow/maps_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/ow/maps"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let maps = client.execute(ListMaps::default()).await.unwrap();
+
+    assert_eq!(maps.len(), 2);
+    assert_eq!(maps[0].name, "Ilios");
+    assert_eq!(maps[0].game_mode, "control");
+    assert_eq!(maps[1].slug, "route-66");
+}
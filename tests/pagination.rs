@@ -0,0 +1,96 @@
+use futures::{StreamExt, TryStreamExt};
+use pandascore::{
+    endpoint::{all::teams::ListTeams, PaginationLimits},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+const NEXT_LINK: &str = r#"<https://api.pandascore.co/teams?page=2&per_page=6>; rel="next", <https://api.pandascore.co/teams?page=5&per_page=6>; rel="last""#;
+
+#[tokio::test]
+async fn test_paginate_single_page() {
+    let client = MockClient::new(include_bytes!("./fixtures/list_teams.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/teams"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let teams: Vec<_> = client
+        .paginate(ListTeams::default())
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(teams.len(), 6);
+}
+
+#[tokio::test]
+async fn test_paginate_max_pages() {
+    let client = MockClient::new(include_bytes!("./fixtures/list_teams.json"))
+        .header("Link", NEXT_LINK)
+        .header("X-Total", "30")
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/teams"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let limits = PaginationLimits::builder().max_pages(3).build();
+    let pages: Vec<_> = client
+        .paginate_pages(ListTeams::default(), limits)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(pages.len(), 3);
+    assert!(pages.iter().all(|page| page.total == 30));
+    assert!(pages.iter().all(|page| page.len() == 6));
+
+    let teams: Vec<_> = client
+        .paginate_with(ListTeams::default(), limits)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(teams.len(), 18);
+}
+
+#[tokio::test]
+async fn test_paginate_max_items() {
+    let client = MockClient::new(include_bytes!("./fixtures/list_teams.json"))
+        .header("Link", NEXT_LINK)
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/teams"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let limits = PaginationLimits::builder().max_items(8).build();
+    let teams: Vec<_> = client
+        .paginate_with(ListTeams::default(), limits)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(teams.len(), 8);
+}
+
+#[tokio::test]
+async fn test_paginate_early_termination() {
+    let client = MockClient::new(include_bytes!("./fixtures/list_teams.json"))
+        .header("Link", NEXT_LINK)
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/teams"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let teams: Vec<_> = client
+        .paginate(ListTeams::default())
+        .take(10)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(teams.len(), 10);
+}
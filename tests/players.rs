@@ -38,7 +38,7 @@ async fn test_get_player() {
 
     let client = Client::new(client, "").unwrap();
 
-    let get_player = GetPlayer(Identifier::Slug("faker"));
+    let get_player = GetPlayer(Identifier::from("faker"));
     let response = client.execute(get_player).await.unwrap();
 
     assert_eq!(response.id, 585);
@@ -53,3 +53,20 @@ async fn test_get_player() {
         Some(Date::from_calendar_date(1996, Month::May, 7).unwrap())
     );
 }
+
+#[tokio::test]
+async fn test_get_player_owned_identifier() {
+    let client = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/players/faker"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let get_player: GetPlayer<'static> = GetPlayer("faker".parse().unwrap());
+    let response = tokio::spawn(async move { client.execute(get_player).await })
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(response.id, 585);
+}
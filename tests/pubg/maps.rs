@@ -0,0 +1,18 @@
+use pandascore::{endpoint::pubg::maps::ListMaps, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_maps() {
+    let client = MockClient::new(include_bytes!("../fixtures/pubg/maps_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/pubg/maps"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let maps = client.execute(ListMaps::default()).await.unwrap();
+
+    assert_eq!(maps.len(), 2);
+    assert_eq!(maps[0].name, "Erangel");
+    assert_eq!(maps[1].image_url, None);
+}
@@ -0,0 +1,17 @@
+use pandascore::{endpoint::r6siege::maps::ListMaps, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_maps() {
+    let client = MockClient::new(include_bytes!("../fixtures/r6siege/maps_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/r6siege/maps"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let maps = client.execute(ListMaps::default()).await.unwrap();
+
+    assert_eq!(maps.len(), 1);
+    assert_eq!(maps[0].name, "Clubhouse");
+}
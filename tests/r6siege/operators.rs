@@ -0,0 +1,21 @@
+use pandascore::{
+    endpoint::r6siege::operators::ListOperators, model::r6siege::operator::OperatorSide, Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_operators() {
+    let client = MockClient::new(include_bytes!("../fixtures/r6siege/operators_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/r6siege/operators"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let operators = client.execute(ListOperators::default()).await.unwrap();
+
+    assert_eq!(operators.len(), 2);
+    assert_eq!(operators[0].name, "Ash");
+    assert_eq!(operators[0].side, Some(OperatorSide::Attacker));
+    assert_eq!(operators[1].side, Some(OperatorSide::Defender));
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use http::StatusCode;
+use pandascore::{
+    endpoint::{all::players::GetPlayer, EndpointError},
+    Client,
+};
+
+use crate::common::MockClient;
+
+mod common;
+
+#[tokio::test]
+async fn test_rate_limit_remaining() {
+    let client = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .header("X-Rate-Limit-Remaining", "42");
+
+    let client = Client::new(client, "").unwrap();
+    assert_eq!(client.rate_limit_remaining(), None);
+
+    client.execute(GetPlayer::from(1)).await.unwrap();
+    assert_eq!(client.rate_limit_remaining(), Some(42));
+    // Clones share the same quota
+    assert_eq!(client.clone().rate_limit_remaining(), Some(42));
+}
+
+#[tokio::test]
+async fn test_rate_limited() {
+    let client = MockClient::new(br#"{"error":"Too Many Requests"}"#)
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .header("Retry-After", "30")
+        .header("X-Rate-Limit-Remaining", "0");
+
+    let client = Client::new(client, "").unwrap();
+
+    let err = client.execute(GetPlayer::from(1)).await.unwrap_err();
+    assert!(err.is_rate_limited());
+    assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    assert!(matches!(
+        err,
+        EndpointError::RateLimited {
+            retry_after: Some(d)
+        } if d == Duration::from_secs(30)
+    ));
+    assert_eq!(client.rate_limit_remaining(), Some(0));
+}
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use http::StatusCode;
+use pandascore::{endpoint::all::players::GetPlayer, layer::RetryPolicy, Client};
+use tokio::time::Instant;
+use tower::{retry::RetryLayer, Layer};
+
+use crate::common::MockClient;
+
+mod common;
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::builder()
+        .base_delay(Duration::from_secs(1))
+        .jitter(false)
+        .build()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_server_error() {
+    let mock = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .fail(StatusCode::INTERNAL_SERVER_ERROR, &[])
+        .fail(StatusCode::BAD_GATEWAY, &[]);
+    let client = Client::new(RetryLayer::new(policy()).layer(mock.clone()), "").unwrap();
+
+    let start = Instant::now();
+    let player = client.execute(GetPlayer::from(1)).await.unwrap();
+
+    assert_eq!(player.id, 585);
+    assert_eq!(mock.calls(), 3);
+    // 1s + 2s of backoff
+    assert_eq!(start.elapsed(), Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_after() {
+    let mock = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .fail(StatusCode::TOO_MANY_REQUESTS, &[("Retry-After", "10")]);
+    let client = Client::new(RetryLayer::new(policy()).layer(mock.clone()), "").unwrap();
+
+    let start = Instant::now();
+    client.execute(GetPlayer::from(1)).await.unwrap();
+
+    assert_eq!(mock.calls(), 2);
+    assert_eq!(start.elapsed(), Duration::from_secs(10));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_max_retries() {
+    let mock = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .fail(StatusCode::SERVICE_UNAVAILABLE, &[])
+        .fail(StatusCode::SERVICE_UNAVAILABLE, &[])
+        .fail(StatusCode::SERVICE_UNAVAILABLE, &[]);
+    let policy = RetryPolicy::builder()
+        .max_retries(2)
+        .base_delay(Duration::from_secs(1))
+        .jitter(false)
+        .build();
+    let client = Client::new(RetryLayer::new(policy).layer(mock.clone()), "").unwrap();
+
+    let err = client.execute(GetPlayer::from(1)).await.unwrap_err();
+
+    assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+    assert_eq!(mock.calls(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_no_retry_client_error() {
+    let mock = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .fail(StatusCode::NOT_FOUND, &[]);
+    let client = Client::new(RetryLayer::new(policy()).layer(mock.clone()), "").unwrap();
+
+    let start = Instant::now();
+    let err = client.execute(GetPlayer::from(1)).await.unwrap_err();
+
+    assert!(err.is_not_found());
+    assert_eq!(mock.calls(), 1);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
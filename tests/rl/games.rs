@@ -0,0 +1,57 @@
+use pandascore::{
+    endpoint::rl::games::{GetGame, ListMatchGames},
+    model::{game::GameStatus, rl::game::Side},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_get_game() {
+    let client = MockClient::new(include_bytes!("../fixtures/rl/games_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/rl/games/118402"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let game = client.execute(GetGame(118_402)).await.unwrap();
+
+    assert_eq!(game.id, 118_402);
+    assert_eq!(game.length, Some(451));
+
+    let blue = game.team(Side::Blue).unwrap();
+    assert_eq!(blue.team.acronym.as_deref(), Some("VIT"));
+    let core = blue.stats.unwrap().core;
+    assert_eq!(core.goals, 4);
+    assert_eq!(core.saves, 5);
+    assert_eq!(core.shots, 11);
+
+    let zen = game.players_of(3212).next().unwrap();
+    assert_eq!(zen.player.name, "zen");
+    let stats = zen.stats.unwrap();
+    assert_eq!(stats.core.score, 612);
+    assert_eq!(stats.core.shooting_percentage, Some(40.0));
+    let boost = stats.boost.unwrap();
+    assert_eq!(boost.amount_collected, Some(2210));
+    assert_eq!(boost.per_minute, Some(380.2));
+    assert_eq!(stats.demo.unwrap().inflicted, 2);
+
+    let monkey = game.players_of(3213).next().unwrap();
+    assert_eq!(monkey.stats.unwrap().boost, None);
+}
+
+#[tokio::test]
+async fn test_list_match_games() {
+    let client = MockClient::new(include_bytes!("../fixtures/rl/match_games_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/rl/matches/590910/games"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = ListMatchGames::builder().match_id(590_910).build();
+    let games = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[1].status, GameStatus::NotStarted);
+    assert_eq!(games[1].team(Side::Orange).unwrap().stats, None);
+}
@@ -1,4 +1,4 @@
-use pandascore::{endpoint::rl::leagues::ListLeagues, Client};
+use pandascore::{endpoint::rl::leagues::ListLeagues, model::VideoGameSlug, Client};
 
 use crate::common::{Expectation, MockClient};
 
@@ -18,5 +18,5 @@ async fn test_list_leagues() {
     assert_eq!(items[0].slug, "rl-rle-gg-roster-royale");
     assert_eq!(items[0].series.len(), 1);
     assert_eq!(items[0].video_game.name, "Rocket League");
-    assert_eq!(items[0].video_game.slug, "rl");
+    assert_eq!(items[0].video_game.slug, VideoGameSlug::RocketLeague);
 }
@@ -0,0 +1,130 @@
+use std::fmt::Debug;
+
+use pandascore::model::{
+    bracket::TournamentBracket,
+    csgo, dota2,
+    incident::Incident,
+    league::League,
+    live::Live,
+    lol,
+    matches::Match,
+    ow,
+    player::Player,
+    pubg, r6siege, rl,
+    series::Series,
+    team::Team,
+    tournament::{Tournament, TournamentRosters, TournamentStanding},
+    valorant, VideoGame, VideoGameVersion,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Asserts that `deserialize(serialize(x)) == x` for the given fixture.
+fn assert_round_trip<T>(json: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let value: T = serde_json::from_str(json).unwrap();
+    let serialized = serde_json::to_string(&value).unwrap();
+    let deserialized: T = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+macro_rules! round_trip_tests {
+    ($($name:ident: $ty:ty => $fixture:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_round_trip::<$ty>(include_str!(concat!("./fixtures/", $fixture)));
+            }
+        )*
+    };
+}
+
+round_trip_tests! {
+    test_round_trip_incidents: Vec<Incident> => "incidents_list.json",
+    test_round_trip_league: League => "get_league.json",
+    test_round_trip_leagues: Vec<League> => "list_leagues.json",
+    test_round_trip_league_matches: Vec<Match> => "get_league_matches.json",
+    test_round_trip_league_series: Vec<Series> => "list_league_series.json",
+    test_round_trip_lives: Vec<Live> => "lives_list.json",
+    test_round_trip_match: Match => "match_get.json",
+    test_round_trip_match_details: Match => "match_de.json",
+    test_round_trip_player: Player => "get_player.json",
+    test_round_trip_players: Vec<Player> => "list_players.json",
+    test_round_trip_series: Series => "series_get.json",
+    test_round_trip_series_list: Vec<Series> => "series_list.json",
+    test_round_trip_team: Team => "get_team.json",
+    test_round_trip_teams: Vec<Team> => "list_teams.json",
+    test_round_trip_tournament_rosters: TournamentRosters => "tournaments_rosters_get.json",
+    test_round_trip_tournament_bracket_standings: Vec<TournamentStanding> => "tournaments_standings_bracket_get.json",
+    test_round_trip_tournament_group_standings: Vec<TournamentStanding> => "tournaments_standings_group_get.json",
+    test_round_trip_videogame: VideoGame => "videogame_get.json",
+    test_round_trip_videogames: Vec<VideoGame> => "videogames_list.json",
+    test_round_trip_videogame_versions: Vec<VideoGameVersion> => "videogame_versions_list.json",
+
+    test_round_trip_csgo_leagues: Vec<League> => "csgo/leagues_list.json",
+    test_round_trip_csgo_map: csgo::map::Map => "csgo/maps_get.json",
+    test_round_trip_csgo_maps: Vec<csgo::map::Map> => "csgo/maps_list.json",
+    test_round_trip_csgo_rounds: Vec<csgo::round::Round> => "csgo/rounds_list.json",
+    test_round_trip_csgo_weapon: csgo::weapon::Weapon => "csgo/weapons_get.json",
+    test_round_trip_csgo_weapons: Vec<csgo::weapon::Weapon> => "csgo/weapons_list.json",
+
+    test_round_trip_dota2_abilities: Vec<dota2::ability::Ability> => "dota2/abilities_list.json",
+    test_round_trip_dota2_hero: dota2::hero::Hero => "dota2/heroes_get.json",
+    test_round_trip_dota2_heroes: Vec<dota2::hero::Hero> => "dota2/heroes_list.json",
+    test_round_trip_dota2_items: Vec<dota2::item::Item> => "dota2/items_list.json",
+
+    test_round_trip_lol_game: lol::game::Game => "lol/games_get.json",
+    test_round_trip_lol_games: Vec<lol::game::Game> => "lol/match_games_list.json",
+    test_round_trip_lol_item: lol::item::Item => "lol/items_get.json",
+    test_round_trip_lol_items: Vec<lol::item::Item> => "lol/items_list.json",
+    test_round_trip_lol_leagues: Vec<League> => "lol/leagues_list.json",
+    test_round_trip_lol_matches: Vec<Match> => "lol/matches_list.json",
+    test_round_trip_lol_player_stats: lol::stats::PlayerStats => "lol/player_stats_get.json",
+    test_round_trip_lol_players: Vec<Player> => "lol/players_list.json",
+    test_round_trip_lol_series: Vec<Series> => "lol/series_list.json",
+    test_round_trip_lol_spell: lol::spell::Spell => "lol/spells_get.json",
+    test_round_trip_lol_spells: Vec<lol::spell::Spell> => "lol/spells_list.json",
+    test_round_trip_lol_team_stats: lol::stats::TeamStats => "lol/team_stats_get.json",
+    test_round_trip_lol_teams: Vec<Team> => "lol/teams_list.json",
+    test_round_trip_lol_tournaments: Vec<Tournament> => "lol/tournaments_list.json",
+
+    test_round_trip_ow_hero: ow::hero::Hero => "ow/heroes_get.json",
+    test_round_trip_ow_heroes: Vec<ow::hero::Hero> => "ow/heroes_list.json",
+    test_round_trip_ow_maps: Vec<ow::map::Map> => "ow/maps_list.json",
+
+    test_round_trip_pubg_maps: Vec<pubg::map::Map> => "pubg/maps_list.json",
+
+    test_round_trip_r6siege_maps: Vec<r6siege::map::Map> => "r6siege/maps_list.json",
+    test_round_trip_r6siege_operators: Vec<r6siege::operator::Operator> => "r6siege/operators_list.json",
+
+    test_round_trip_rl_game: rl::game::Game => "rl/games_get.json",
+    test_round_trip_rl_games: Vec<rl::game::Game> => "rl/match_games_list.json",
+    test_round_trip_rl_leagues: Vec<League> => "rl/leagues_list.json",
+    test_round_trip_rl_matches: Vec<Match> => "rl/matches_list.json",
+    test_round_trip_rl_players: Vec<Player> => "rl/players_list.json",
+    test_round_trip_rl_series: Vec<Series> => "rl/series_list.json",
+    test_round_trip_rl_teams: Vec<Team> => "rl/teams_list.json",
+    test_round_trip_rl_tournaments: Vec<Tournament> => "rl/tournaments_list.json",
+
+    test_round_trip_valorant_abilities: Vec<valorant::ability::Ability> => "valorant/abilities_list.json",
+    test_round_trip_valorant_agent: valorant::agent::Agent => "valorant/agents_get.json",
+    test_round_trip_valorant_agents: Vec<valorant::agent::Agent> => "valorant/agents_list.json",
+    test_round_trip_valorant_maps: Vec<valorant::map::Map> => "valorant/maps_list.json",
+    test_round_trip_valorant_weapons: Vec<valorant::weapon::Weapon> => "valorant/weapons_list.json",
+}
+
+#[test]
+fn test_round_trip_tournament_bracket() {
+    let json = include_str!("./fixtures/tournaments_brackets_get.json");
+    let bracket: TournamentBracket = serde_json::from_str(json).unwrap();
+    let serialized = serde_json::to_string(&bracket).unwrap();
+    let deserialized: TournamentBracket = serde_json::from_str(&serialized).unwrap();
+
+    assert!(deserialized
+        .as_ref()
+        .node_weights()
+        .eq(bracket.as_ref().node_weights()));
+    assert_eq!(deserialized.as_ref().edge_count(), 2);
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+}
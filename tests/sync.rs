@@ -0,0 +1,107 @@
+use pandascore::{
+    model::incident::IncidentType,
+    sync::{
+        Checkpoint, CheckpointStore, JsonFileCheckpointStore, MemoryCheckpointStore, SyncEvent,
+        Syncer,
+    },
+    Client,
+};
+use time::macros::datetime;
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+#[tokio::test]
+async fn test_sync_poll() {
+    let client = MockClient::new(include_bytes!("./fixtures/incidents_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/incidents"));
+
+    let client = Client::new(client, "").unwrap();
+    let store = MemoryCheckpointStore::new();
+
+    let mut syncer = Syncer::builder()
+        .client(client)
+        .store(store.clone())
+        .build();
+
+    let events = syncer.poll().await.unwrap();
+    assert_eq!(events.len(), 4);
+
+    // Events are emitted in chronological order.
+    assert!(matches!(events[0], SyncEvent::Added(_)));
+    assert!(matches!(events[1], SyncEvent::Changed(_)));
+    assert!(matches!(events[2], SyncEvent::Changed(_)));
+    let SyncEvent::Deleted { r#type, id, .. } = &events[3] else {
+        panic!("expected deletion, got {:?}", events[3]);
+    };
+    assert_eq!(*r#type, IncidentType::Tournament);
+    assert_eq!(*id, 14032);
+
+    let checkpoint = store.get().unwrap();
+    assert_eq!(checkpoint.modified_at, datetime!(2024-09-10 08:00:00 UTC));
+    assert_eq!(checkpoint.seen, [(IncidentType::Tournament, 14032)]);
+
+    // The mock returns the same incidents again, all of them were already seen.
+    let events = syncer.poll().await.unwrap();
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_first_poll_single_page() {
+    let mock = MockClient::new(include_bytes!("./fixtures/incidents_list.json")).header(
+        "Link",
+        r#"<https://api.pandascore.co/incidents?page=2&per_page=100>; rel="next""#,
+    );
+
+    let client = Client::new(mock.clone(), "").unwrap();
+    let mut syncer = Syncer::builder()
+        .client(client)
+        .store(MemoryCheckpointStore::new())
+        .build();
+
+    let events = syncer.poll().await.unwrap();
+    assert_eq!(events.len(), 4);
+    assert_eq!(mock.calls(), 1);
+    assert_eq!(
+        syncer.checkpoint().unwrap().modified_at,
+        datetime!(2024-09-10 08:00:00 UTC)
+    );
+}
+
+#[tokio::test]
+async fn test_sync_resume_from_store() {
+    let client = MockClient::new(include_bytes!("./fixtures/incidents_list.json"))
+        .expect(Expectation::Query("since", "2024-09-08T11:29:41Z"));
+
+    let client = Client::new(client, "").unwrap();
+    let store = MemoryCheckpointStore::new();
+    store
+        .save(&Checkpoint::new(datetime!(2024-09-08 11:29:41 UTC)))
+        .await
+        .unwrap();
+
+    let mut syncer = Syncer::builder().client(client).store(store).build();
+
+    let events = syncer.poll().await.unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[1], SyncEvent::Deleted { .. }));
+}
+
+#[tokio::test]
+async fn test_json_file_checkpoint_store() {
+    let path =
+        std::env::temp_dir().join(format!("pandascore-checkpoint-{}.json", std::process::id()));
+    let store = JsonFileCheckpointStore::new(&path);
+
+    assert_eq!(store.load().await.unwrap(), None);
+
+    let mut checkpoint = Checkpoint::new(datetime!(2024-09-10 08:00:00 UTC));
+    checkpoint.seen.push((IncidentType::Tournament, 14032));
+    store.save(&checkpoint).await.unwrap();
+
+    assert_eq!(store.load().await.unwrap(), Some(checkpoint));
+
+    std::fs::remove_file(path).unwrap();
+}
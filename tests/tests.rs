@@ -1,17 +1,47 @@
 mod common;
 
+mod csgo {
+    mod games;
+    mod leagues;
+    mod maps;
+    mod weapons;
+}
+
+mod dota2 {
+    mod abilities;
+    mod heroes;
+    mod items;
+}
+
 mod lol {
+    mod games;
     mod items;
     mod leagues;
     mod matches;
     mod players;
     mod series;
     mod spells;
+    mod stats;
     mod teams;
     mod tournaments;
 }
 
+mod ow {
+    mod heroes;
+    mod maps;
+}
+
+mod pubg {
+    mod maps;
+}
+
+mod r6siege {
+    mod maps;
+    mod operators;
+}
+
 mod rl {
+    mod games;
     mod leagues;
     mod matches;
     mod players;
@@ -19,3 +49,10 @@ mod rl {
     mod teams;
     mod tournaments;
 }
+
+mod valorant {
+    mod abilities;
+    mod agents;
+    mod maps;
+    mod weapons;
+}
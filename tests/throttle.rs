@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use pandascore::{endpoint::all::players::GetPlayer, layer::ThrottleLayer, Client};
+use tokio::time::Instant;
+use tower::Layer;
+
+use crate::common::MockClient;
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_spacing() {
+    let transport = ThrottleLayer::new(2, Duration::from_secs(1)).layer(MockClient::new(
+        include_bytes!("./fixtures/get_player.json"),
+    ));
+    let client = Client::new(transport, "").unwrap();
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        client.execute(GetPlayer::from(1)).await.unwrap();
+    }
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_exhausted() {
+    let mock = MockClient::new(include_bytes!("./fixtures/get_player.json"))
+        .header("X-Rate-Limit-Remaining", "0");
+    let transport = ThrottleLayer::hourly(1000).layer(mock);
+    let client = Client::new(transport, "").unwrap();
+
+    let start = Instant::now();
+    client.execute(GetPlayer::from(1)).await.unwrap();
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    client.execute(GetPlayer::from(1)).await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(60 * 60));
+}
@@ -1,5 +1,7 @@
 use pandascore::{
-    endpoint::all::tournament::{GetTournamentRosters, GetTournamentStandings},
+    endpoint::all::tournament::{
+        GetTournamentBracket, GetTournamentRosters, GetTournamentStandings,
+    },
     model::tournament::{TournamentRosters, TournamentStanding},
     Client,
 };
@@ -78,3 +80,21 @@ async fn test_get_tournament_standings_group() {
     assert_eq!(group.total, 18);
     assert_eq!(group.team.name, "Fnatic");
 }
+
+#[tokio::test]
+async fn test_get_tournament_bracket() {
+    let client = MockClient::new(include_bytes!("./fixtures/tournaments_brackets_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/tournaments/11808/brackets"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let bracket = client
+        .execute(GetTournamentBracket::from(11808))
+        .await
+        .unwrap();
+
+    assert!(bracket.is_acyclic());
+    let final_match = bracket.get(841_976).unwrap();
+    assert_eq!(final_match.previous_matches.len(), 2);
+}
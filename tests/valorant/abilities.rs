@@ -0,0 +1,21 @@
+use pandascore::{endpoint::valorant::abilities::ListAbilities, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_abilities() {
+    let client = MockClient::new(include_bytes!("../fixtures/valorant/abilities_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/valorant/abilities"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let abilities = client.execute(ListAbilities::default()).await.unwrap();
+
+    assert_eq!(abilities.len(), 2);
+    assert_eq!(abilities[0].name, "Blade Storm");
+    assert_eq!(abilities[0].ability_type, "ultimate");
+    assert_eq!(abilities[0].creds, None);
+    assert_eq!(abilities[1].agent_id, Some(1));
+    assert_eq!(abilities[1].creds, Some(200));
+}
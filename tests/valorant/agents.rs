@@ -0,0 +1,36 @@
+use pandascore::{
+    endpoint::valorant::agents::{GetAgent, ListAgents},
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_agents() {
+    let client = MockClient::new(include_bytes!("../fixtures/valorant/agents_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/valorant/agents"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let agents = client.execute(ListAgents::default()).await.unwrap();
+
+    assert_eq!(agents.len(), 2);
+    assert_eq!(agents[0].name, "Jett");
+    assert_eq!(agents[0].video_game_versions, ["9.04", "9.03"]);
+    assert_eq!(agents[1].portrait_url, None);
+}
+
+#[tokio::test]
+async fn test_get_agent() {
+    let client = MockClient::new(include_bytes!("../fixtures/valorant/agents_get.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/valorant/agents/5"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let agent = client.execute(GetAgent::from(5)).await.unwrap();
+
+    assert_eq!(agent.id, 5);
+    assert_eq!(agent.name, "Sova");
+}
@@ -0,0 +1,18 @@
+use pandascore::{endpoint::valorant::maps::ListMaps, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_maps() {
+    let client = MockClient::new(include_bytes!("../fixtures/valorant/maps_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/valorant/maps"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let maps = client.execute(ListMaps::default()).await.unwrap();
+
+    assert_eq!(maps.len(), 2);
+    assert_eq!(maps[0].name, "Ascent");
+    assert_eq!(maps[1].id, 7);
+}
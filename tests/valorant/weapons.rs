@@ -0,0 +1,19 @@
+use pandascore::{endpoint::valorant::weapons::ListWeapons, Client};
+
+use crate::common::{Expectation, MockClient};
+
+#[tokio::test]
+async fn test_list_weapons() {
+    let client = MockClient::new(include_bytes!("../fixtures/valorant/weapons_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/valorant/weapons"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let weapons = client.execute(ListWeapons::default()).await.unwrap();
+
+    assert_eq!(weapons.len(), 2);
+    assert_eq!(weapons[0].name, "Vandal");
+    assert_eq!(weapons[0].cost, Some(2900));
+    assert_eq!(weapons[1].cost, None);
+}
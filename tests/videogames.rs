@@ -0,0 +1,73 @@
+use pandascore::{
+    endpoint::{
+        all::videogames::{GetVideoGame, ListVideoGameVersions, ListVideoGames},
+        options::MatchOptions,
+        CollectionOptions,
+    },
+    model::VideoGameSlug,
+    Client,
+};
+
+use crate::common::{Expectation, MockClient};
+
+mod common;
+
+#[tokio::test]
+async fn test_list_video_games() {
+    let client = MockClient::new(include_bytes!("./fixtures/videogames_list.json"))
+        .expect(Expectation::Method(reqwest::Method::GET))
+        .expect(Expectation::Path("/videogames"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let response = client.execute(ListVideoGames::default()).await.unwrap();
+
+    assert_eq!(response.len(), 6);
+    assert_eq!(response[0].slug, VideoGameSlug::LeagueOfLegends);
+    assert_eq!(response[1].slug, VideoGameSlug::CounterStrike);
+    assert_eq!(response[3].slug, VideoGameSlug::RocketLeague);
+    assert_eq!(response[4].current_version.as_deref(), Some("9.04"));
+    assert_eq!(response[5].slug, VideoGameSlug::Other("new-game".into()));
+}
+
+#[tokio::test]
+async fn test_get_video_game() {
+    let client = MockClient::new(include_bytes!("./fixtures/videogame_get.json"))
+        .expect(Expectation::Path("/videogames/valorant"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let response = client
+        .execute(GetVideoGame::from(&VideoGameSlug::Valorant))
+        .await
+        .unwrap();
+
+    assert_eq!(response.id, 26);
+    assert_eq!(response.slug, VideoGameSlug::Valorant);
+}
+
+#[tokio::test]
+async fn test_list_video_game_versions() {
+    let client = MockClient::new(include_bytes!("./fixtures/videogame_versions_list.json"))
+        .expect(Expectation::Path("/videogames/league-of-legends/versions"));
+
+    let client = Client::new(client, "").unwrap();
+
+    let endpoint = ListVideoGameVersions::builder()
+        .id(&VideoGameSlug::LeagueOfLegends)
+        .build();
+    let response = client.execute(endpoint).await.unwrap();
+
+    assert_eq!(response.len(), 3);
+    assert!(response[0].current);
+    assert_eq!(response[0].name, "14.18.1");
+}
+
+#[test]
+fn test_filter_videogame() {
+    let options: CollectionOptions = MatchOptions::new()
+        .filter_videogame(VideoGameSlug::Dota2)
+        .into();
+
+    assert_eq!(options.to_query_string(), "filter%5Bvideogame%5D=dota-2");
+}